@@ -46,8 +46,10 @@ import {
   getCreateInstruction,
   getSwapInstruction,
   getCloseInstruction,
+  getRegisterReferrerInstruction,
   AQUA_SWAP_PROGRAM_ADDRESS
 } from './clients/js/src/generated'
+import { findConfigPda, findReferrerPda, findSwapPda, withSwapAccounts, withoutLockAccounts } from './aqua-swap-accounts';
 
 const RPC_URL = 'https://api.devnet.solana.com';
 const WS_URL = 'wss://api.devnet.solana.com';
@@ -104,10 +106,8 @@ async function main() {
   const uuidStr = uuidv4();
   const uuidBytes = uuidParse(uuidStr);
   const uuid = toBigIntLE(Buffer.from(uuidBytes));
-  const [swapPda, bumpSeed] = await getProgramDerivedAddress({
-    programAddress: AQUA_SWAP_PROGRAM_ADDRESS,
-    seeds: [uuidBytes],
-  });
+  const [swapPda, bumpSeed] = await findSwapPda(payer.address, uuidBytes);
+  const [configPda] = await findConfigPda();
   console.log('📍 Swap PDA:', swapPda, 'bump:', bumpSeed);
 
   // Create BASE and QUOTE mints (SPL-only)
@@ -173,11 +173,19 @@ async function main() {
       swapAcc: address(swapPda),
       vaultBaseAcc: address(baseVaultAta),
       vaultQuoteAcc: address(quoteVaultAta),
+      configAcc: configPda,
       createData: {
         uuid,
         price: 10_000_000_000, // 10 quote per 1 base (scaled by 1e9)
-        bonusBase: Number(BONUS_PERCENT),
-        bonusQuote: Number(BONUS_PERCENT),
+        bonusBase: BONUS_PERCENT,
+        bonusQuote: BONUS_PERCENT,
+        bonusBaseMax: BONUS_PERCENT,
+        bonusQuoteMax: BONUS_PERCENT,
+        bonusLockPeriod: 0, // base bonus paid instantly
+        referrerApproval: 0, // referrers are approved on registration
+        createVault: 0,
+        initialDeposit: 0,
+        priceExponent: 0,
         bumpSeed: Number(bumpSeed),
       },
     });
@@ -237,6 +245,29 @@ async function main() {
   }
   console.log('✅ Bonus recipient ATAs created (quote and base)');
 
+  // Register the bonus recipient as a referrer of the pool (bonuses only go to referrers)
+  const [referrerPda] = await findReferrerPda(swapPda, bonusRecipient.address);
+  {
+    const { value: bh } = await rpc.getLatestBlockhash().send();
+    const registerIx = getRegisterReferrerInstruction({
+      walletAcc: bonusRecipient,
+      swapAcc: address(swapPda),
+      referrerAcc: referrerPda,
+    });
+    await pipe(
+      createTransactionMessage({ version: 0 }),
+      (tx) => setTransactionMessageFeePayerSigner(bonusRecipient, tx),
+      (tx) => setTransactionMessageLifetimeUsingBlockhash(bh, tx),
+      (tx) => appendTransactionMessageInstructions([registerIx], tx),
+      async (tx) => {
+        const signed = await signTransactionMessageWithSigners(tx);
+        await sendAndConfirmTransaction(signed as any, { commitment: 'confirmed' });
+        return getSignatureFromTransaction(signed);
+      }
+    );
+  }
+  console.log('✅ Bonus recipient registered as referrer:', referrerPda);
+
   // Mint quote to swap user
   {
     const { value: bh } = await rpc.getLatestBlockhash().send();
@@ -276,11 +307,12 @@ async function main() {
     bonusBase: await getAmount(bonusBaseAta),
   };
 
-  // Execute swap (note: pass bonus_base_acc = base vault; bonus_quote_acc = bonus recipient)
+  // Execute swap (bonus_base_acc/bonus_quote_acc are the referrer's ATAs)
   const swapAmountUnits = BigInt(SWAP_QUOTE_TOKENS) * BigInt(10 ** DECIMALS);
   {
     const { value: bh } = await rpc.getLatestBlockhash().send();
-    const swapIx = getSwapInstruction({
+    // SPL-quoted pool with unlocked bonuses and no protocol fee
+    const swapIx = withSwapAccounts(getSwapInstruction({
       userAcc: swapUser,
       swapAcc: address(swapPda),
       vaultBaseAcc: address(baseVaultAta),
@@ -289,11 +321,12 @@ async function main() {
       userQuoteAcc: address(swapUserQuoteAta),
       baseMintAcc: address(baseMint.address),
       quoteMintAcc: address(quoteMint.address),
+      configAcc: configPda,
       bonusBaseAcc: address(bonusBaseAta),
       bonusQuoteAcc: address(bonusQuoteAta),
-      wsolTempAcc: address(swapUserQuoteAta), // unused for SPL path
+      referrerAcc: referrerPda,
       swapData: { quoteIn: swapAmountUnits },
-    });
+    }), { bonus: true });
     const sig = await pipe(
       createTransactionMessage({ version: 0 }),
       (tx) => setTransactionMessageFeePayerSigner(swapUser, tx),
@@ -359,7 +392,7 @@ async function main() {
     const { value: bh } = await rpc.getLatestBlockhash().send();
     const [payerBaseAta] = await findAssociatedTokenPda({ mint: baseMint.address, owner: payer.address, tokenProgram: TOKEN_PROGRAM_ADDRESS });
     const createPayerBaseAtaIx = await getCreateAssociatedTokenIdempotentInstructionAsync({ mint: baseMint.address, payer, owner: payer.address });
-    const closeIx = getCloseInstruction({ ownerAcc: payer, swapAcc: address(swapPda), vaultBaseAcc: address(baseVaultAta), ownerBaseAcc: address(payerBaseAta) });
+    const closeIx = withoutLockAccounts(getCloseInstruction({ ownerAcc: payer, swapAcc: address(swapPda), vaultBaseAcc: address(baseVaultAta), ownerBaseAcc: address(payerBaseAta), baseMintAcc: baseMint.address }));
     await pipe(
       createTransactionMessage({ version: 0 }),
       (tx) => setTransactionMessageFeePayerSigner(payer, tx),
@@ -44,8 +44,10 @@ import {
   getCreateInstruction,
   getSwapInstruction,
   getCloseInstruction,
+  getRegisterReferrerInstruction,
   AQUA_SWAP_PROGRAM_ADDRESS
 } from './clients/js/src/generated';
+import { findConfigPda, findReferrerPda, findSwapPda, withSwapAccounts, withoutLockAccounts } from './aqua-swap-accounts';
 
 const WSOL_MINT = 'So11111111111111111111111111111111111111112';
 const RPC_URL = 'https://api.devnet.solana.com';
@@ -100,7 +102,8 @@ async function main() {
   const uuidStr = uuidv4();
   const uuidBytes = uuidParse(uuidStr);
   const uuid = toBigIntLE(Buffer.from(uuidBytes));
-  const [swapPda, bumpSeed] = await getProgramDerivedAddress({ programAddress: AQUA_SWAP_PROGRAM_ADDRESS, seeds: [uuidBytes] });
+  const [swapPda, bumpSeed] = await findSwapPda(payer.address, uuidBytes);
+  const [configPda] = await findConfigPda();
   console.log('📍 Swap PDA:', swapPda, 'bump:', bumpSeed);
 
   // Create BASE mint
@@ -157,17 +160,23 @@ async function main() {
     const { value: bh } = await rpc.getLatestBlockhash().send();
     const createIx = getCreateInstruction({
       ownerAcc: payer,
-      verifyAcc: payer.address,
       swapAcc: address(swapPda),
       vaultBaseAcc: address(baseVaultAta),
       vaultQuoteAcc: address(quoteVaultWsolAta), // WSOL ATA; program stores its owner for SOL transfers
+      configAcc: configPda,
       createData: {
         uuid,
         price: 10_000_000_000, // 10 quote per 1 base (scaled by 1e9)
-        bonusBase: Number(BONUS_PERCENT),
-        bonusQuote: Number(BONUS_PERCENT),
+        bonusBase: BONUS_PERCENT,
+        bonusQuote: BONUS_PERCENT,
+        bonusBaseMax: BONUS_PERCENT,
+        bonusQuoteMax: BONUS_PERCENT,
+        bonusLockPeriod: 0, // base bonus paid instantly
+        referrerApproval: 0, // referrers are approved on registration
+        createVault: 0,
+        initialDeposit: 0,
+        priceExponent: 0,
         bumpSeed: Number(bumpSeed),
-        requireVerify: false,
       },
     });
     await pipe(
@@ -224,6 +233,25 @@ async function main() {
   }
   console.log('✅ Bonus base ATA prepared');
 
+  // Register the bonus recipient as a referrer of the pool (bonuses only go to referrers)
+  const [referrerPda] = await findReferrerPda(swapPda, bonusRecipient.address);
+  {
+    const { value: bh } = await rpc.getLatestBlockhash().send();
+    const registerIx = getRegisterReferrerInstruction({ walletAcc: bonusRecipient, swapAcc: address(swapPda), referrerAcc: referrerPda });
+    await pipe(
+      createTransactionMessage({ version: 0 }),
+      (tx) => setTransactionMessageFeePayerSigner(bonusRecipient, tx),
+      (tx) => setTransactionMessageLifetimeUsingBlockhash(bh, tx),
+      (tx) => appendTransactionMessageInstructions([registerIx], tx),
+      async (tx) => {
+        const signed = await signTransactionMessageWithSigners(tx);
+        await sendAndConfirmTransaction(signed as any, { commitment: 'confirmed' });
+        return null as unknown as string;
+      }
+    );
+  }
+  console.log('✅ Bonus recipient registered as referrer');
+
   // Compute WSOL temp ATA owned by swap PDA
   const [wsolTempAta] = await findAssociatedTokenPda({ mint: WSOL_MINT, owner: swapPda, tokenProgram: TOKEN_PROGRAM_ADDRESS });
 
@@ -249,8 +277,9 @@ async function main() {
   // Wrap SOL into user WSOL ATA
   instructions.push(getTransferSolInstruction({ source: swapUser, destination: userWsolAta, amount: swapAmountUnits }));
   instructions.push(getSyncNativeInstruction({ account: userWsolAta }));
-  // Swap instruction (program will create wsolTempAcc idempotently if needed)
-  const swapIx = getSwapInstruction({
+  // Swap instruction (program will create wsolTempAcc idempotently if needed);
+  // SOL-quoted pool with unlocked bonuses and no protocol fee
+  const swapIx = withSwapAccounts(getSwapInstruction({
     userAcc: swapUser,
     swapAcc: address(swapPda),
     vaultBaseAcc: address(baseVaultAta),
@@ -259,11 +288,13 @@ async function main() {
     userQuoteAcc: address(userWsolAta),
     baseMintAcc: address(baseMint.address),
     quoteMintAcc: address(WSOL_MINT),
+    configAcc: configPda,
+    wsolTempAcc: address(wsolTempAta), // WSOL ATA owned by swap PDA
     bonusBaseAcc: address(bonusBaseAta),
     bonusQuoteAcc: address(bonusRecipient.address), // system account receives SOL bonus
-    wsolTempAcc: address(wsolTempAta), // WSOL ATA owned by swap PDA
+    referrerAcc: referrerPda,
     swapData: { quoteIn: swapAmountUnits },
-  });
+  }), { wsol: true, bonus: true });
   instructions.push(swapIx);
 
   const sig = await pipe(
@@ -319,7 +350,7 @@ async function main() {
     const [payerBaseAta] = await findAssociatedTokenPda({ mint: baseMint.address, owner: payer.address, tokenProgram: TOKEN_PROGRAM_ADDRESS });
     const { value: bh2 } = await rpc.getLatestBlockhash().send();
     const ixCreatePayerBase = await getCreateAssociatedTokenIdempotentInstructionAsync({ mint: baseMint.address, payer, owner: payer.address });
-    const closeIx = getCloseInstruction({ ownerAcc: payer, swapAcc: address(swapPda), vaultBaseAcc: address(baseVaultAta), ownerBaseAcc: address(payerBaseAta) });
+    const closeIx = withoutLockAccounts(getCloseInstruction({ ownerAcc: payer, swapAcc: address(swapPda), vaultBaseAcc: address(baseVaultAta), ownerBaseAcc: address(payerBaseAta), baseMintAcc: baseMint.address }));
     await pipe(
       createTransactionMessage({ version: 0 }),
       (tx) => setTransactionMessageFeePayerSigner(payer, tx),
@@ -47,6 +47,7 @@ import { getCreateInstruction } from './clients/js/src/generated/instructions/cr
 import { getSwapInstruction } from './clients/js/src/generated/instructions/swap';
 import { getCloseInstruction } from './clients/js/src/generated/instructions/close';
 import { AQUA_SWAP_PROGRAM_ADDRESS } from './clients/js/src/generated/programs/aquaSwap';
+import { findConfigPda, findSwapPda, withSwapAccounts, withoutLockAccounts } from './aqua-swap-accounts';
 
 // WSOL mint address (same on all networks)
 const WSOL_MINT_ADDRESS = 'So11111111111111111111111111111111111111112';
@@ -81,10 +82,8 @@ async function main() {
   console.log('🆔 UUID (string):', uuidStr);
   console.log('🔢 UUID (u128 bigint):', uuid);
 
-  const [derivedSwapAddress, bumpSeed] = await getProgramDerivedAddress({
-    programAddress: AQUA_SWAP_PROGRAM_ADDRESS,
-    seeds: [uuidBytes],
-  });
+  const [derivedSwapAddress, bumpSeed] = await findSwapPda(payer.address, uuidBytes);
+  const [configAddress] = await findConfigPda();
   console.log('📍 Derived Swap PDA:', derivedSwapAddress);
   console.log('🧷 Bump seed:', bumpSeed);
 
@@ -273,13 +272,20 @@ async function main() {
     ownerAcc: payer,
     swapAcc: address(derivedSwapAddress),
     vaultBaseAcc: address(baseAta),
-    vaultQuoteAcc: address(quoteAta),
-    verifyAcc: payer, // Use payer as verify account
+    vaultQuoteAcc: address(quoteAta), // WSOL ATA; program stores its owner for SOL transfers
+    configAcc: configAddress,
     createData: {
       uuid,              // u128 from parsed UUID string
       price: 10_000n, // 10 quote tokens per 1 base token (scaled by 1e9)
       bonusBase: 0n,     // Set bonus base to 0
       bonusQuote: 0n,    // Set bonus quote to 0
+      bonusBaseMax: 0n,  // No bonuses, so swaps skip the bonus accounts
+      bonusQuoteMax: 0n,
+      bonusLockPeriod: 0n,
+      referrerApproval: 0,
+      createVault: 0,    // Base vault ATA was created above
+      initialDeposit: 0n,
+      priceExponent: 0,
       bumpSeed: Number(bumpSeed) // u8 bump from PDA derivation
     }
   });
@@ -420,19 +426,28 @@ async function main() {
   
   // 4. Execute swap
   console.log('🔄 Adding swap instruction...');
-  const swapIx = getSwapInstruction({
+  // SOL-quoted pool: the vault is the quote owner's system account, and the program
+  // unwraps the user's WSOL through a temporary ATA owned by the swap PDA
+  const [wsolTempAta] = await findAssociatedTokenPda({
+    mint: WSOL_MINT_ADDRESS,
+    owner: derivedSwapAddress,
+    tokenProgram: TOKEN_PROGRAM_ADDRESS,
+  });
+  const swapIx = withSwapAccounts(getSwapInstruction({
     userAcc: swapUser,
     swapAcc: address(derivedSwapAddress),
     vaultBaseAcc: address(baseAta),
-    vaultQuoteAcc: address(quoteAta),
+    vaultQuoteAcc: payer.address, // system account: owner captured from WSOL ATA at create
     userBaseAcc: address(swapUserBaseAta),
     userQuoteAcc: address(swapUserQuoteAta),
     baseMintAcc: address(baseMint.address),
     quoteMintAcc: address(WSOL_MINT_ADDRESS),
+    configAcc: configAddress,
+    wsolTempAcc: wsolTempAta,
     swapData: {
       quoteIn: swapAmount
     }
-  });
+  }), { wsol: true });
   instructions.push(swapIx);
   
   // 5. Close WSOL ATA only if it didn't exist before (clean up)
@@ -627,12 +642,13 @@ async function main() {
 
   {
     const { value: latestBlockhash } = await rpc.getLatestBlockhash().send();
-    const closeIx = getCloseInstruction({
+    const closeIx = withoutLockAccounts(getCloseInstruction({
       ownerAcc: payer,
       swapAcc: address(derivedSwapAddress),
       vaultBaseAcc: address(baseAta),
       ownerBaseAcc: address(payerBaseAta),
-    });
+      baseMintAcc: baseMint.address,
+    }));
 
     const closeSig = await pipe(
       createTransactionMessage({ version: 0 }),
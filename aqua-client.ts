@@ -46,6 +46,7 @@ import {
   getCloseInstruction,
   AQUA_SWAP_PROGRAM_ADDRESS
 } from './clients/js/src/generated'
+import { findConfigPda, findSwapPda, withSwapAccounts, withoutLockAccounts } from './aqua-swap-accounts';
 
 // Optional: program import (not required since instruction includes programAddress by default)
 // import { AQUA_SWAP_PROGRAM_ADDRESS } from './clients/js/src/generated/programs';
@@ -82,10 +83,8 @@ async function main() {
   console.log('🆔 UUID (string):', uuidStr);
   console.log('🔢 UUID (u128 bigint):', uuid);
 
-  const [derivedSwapAddress, bumpSeed] = await getProgramDerivedAddress({
-    programAddress: AQUA_SWAP_PROGRAM_ADDRESS,
-    seeds: [uuidBytes],
-  });
+  const [derivedSwapAddress, bumpSeed] = await findSwapPda(payer.address, uuidBytes);
+  const [configAddress] = await findConfigPda();
   console.log('📍 Derived Swap PDA:', derivedSwapAddress);
   console.log('🧷 Bump seed:', bumpSeed);
 
@@ -324,13 +323,19 @@ async function main() {
     swapAcc: address(derivedSwapAddress),
     vaultBaseAcc: address(baseAta),
     vaultQuoteAcc: address(quoteAta),
-    verifyAcc: payer.address,
+    configAcc: configAddress,
     createData: {
       uuid,              // u128 from parsed UUID string
       price: 10_000_000_000, // 10 quote tokens per 1 base token (scaled by 1e9)
       bonusBase: 0,     // Set bonus base to 0
       bonusQuote: 0,    // Set bonus quote to 0
-      requireVerify: false,
+      bonusBaseMax: 0,  // No bonuses, so swaps skip the bonus accounts
+      bonusQuoteMax: 0,
+      bonusLockPeriod: 0,
+      referrerApproval: 0,
+      createVault: 0,   // Base vault ATA was created above
+      initialDeposit: 0,
+      priceExponent: 0,
       bumpSeed: Number(bumpSeed) // u8 bump from PDA derivation
     },
   });
@@ -460,7 +465,8 @@ async function main() {
   console.log('\n🔄 Executing swap: swap user trades quote tokens for base tokens');
   const swapAmount = BigInt(10) * BigInt(10 ** DECIMALS); // 10 quote tokens
   
-  const swapIx = getSwapInstruction({
+  // SPL-quoted pool without bonuses and no protocol fee: only the fixed accounts
+  const swapIx = withSwapAccounts(getSwapInstruction({
     userAcc: swapUser,
     swapAcc: address(derivedSwapAddress),
     vaultBaseAcc: address(baseAta),
@@ -469,13 +475,11 @@ async function main() {
     userQuoteAcc: address(swapUserQuoteAta),
     baseMintAcc: address(baseMint.address),
     quoteMintAcc: address(quoteMint.address),
-    bonusBaseAcc: address(swapUserBaseAta),
-    bonusQuoteAcc: address(swapUserQuoteAta),
-    wsolTempAcc: address(swapUserQuoteAta),
+    configAcc: configAddress,
     swapData: {
       quoteIn: swapAmount
     }
-  });
+  }), {});
 
   {
     const { value: latestBlockhash } = await rpc.getLatestBlockhash().send();
@@ -649,12 +653,13 @@ async function main() {
 
   {
     const { value: latestBlockhash } = await rpc.getLatestBlockhash().send();
-    const closeIx = getCloseInstruction({
+    const closeIx = withoutLockAccounts(getCloseInstruction({
       ownerAcc: payer,
       swapAcc: address(derivedSwapAddress),
       vaultBaseAcc: address(baseAta),
       ownerBaseAcc: address(payerBaseAta),
-    });
+      baseMintAcc: baseMint.address,
+    }));
 
     const closeSig = await pipe(
       createTransactionMessage({ version: 0 }),
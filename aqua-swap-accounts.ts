@@ -0,0 +1,70 @@
+/**
+ * Shared PDA and account helpers for the Aqua Swap scripts.
+ * - Pools are derived from ["swap", owner, uuid] with the uuid as 16 little-endian bytes
+ * - The program config lives at the ["config"] PDA
+ * - Bonus recipients register at the ["referrer", swap, wallet] PDA
+ * - Generated Swap/Close builders emit every optional account; the program only reads
+ *   the groups a pool needs, so the helpers below keep just those
+ */
+
+import {
+  getAddressEncoder,
+  getProgramDerivedAddress,
+  type Address,
+  type Instruction,
+} from '@solana/kit';
+import { AQUA_SWAP_PROGRAM_ADDRESS } from './clients/js/src/generated';
+
+export async function findSwapPda(owner: Address, uuidBytes: Uint8Array) {
+  return getProgramDerivedAddress({
+    programAddress: AQUA_SWAP_PROGRAM_ADDRESS,
+    seeds: ['swap', getAddressEncoder().encode(owner), uuidBytes],
+  });
+}
+
+export async function findConfigPda() {
+  return getProgramDerivedAddress({
+    programAddress: AQUA_SWAP_PROGRAM_ADDRESS,
+    seeds: ['config'],
+  });
+}
+
+export async function findReferrerPda(swap: Address, wallet: Address) {
+  return getProgramDerivedAddress({
+    programAddress: AQUA_SWAP_PROGRAM_ADDRESS,
+    seeds: ['referrer', getAddressEncoder().encode(swap), getAddressEncoder().encode(wallet)],
+  });
+}
+
+/** Optional account groups following config_acc in Swap, in program order. */
+export type SwapAccountGroups = {
+  /** SOL-quoted pools: wsol_temp_acc, system program, ATA program */
+  wsol?: boolean;
+  /** Pools with bonuses: bonus_base_acc, bonus_quote_acc, referrer_acc */
+  bonus?: boolean;
+  /** Pools locking base bonuses: bonus_lock_acc, system program */
+  bonusLock?: boolean;
+  /** Protocol fee enabled: fee_treasury_acc */
+  feeTreasury?: boolean;
+  /** Separate payer_acc */
+  payer?: boolean;
+};
+
+const SWAP_FIXED_ACCOUNTS = 10;
+
+/** Keep the fixed Swap accounts plus the optional groups the pool reads. */
+export function withSwapAccounts<T extends Instruction>(ix: T, groups: SwapAccountGroups): T {
+  const accounts = ix.accounts ?? [];
+  const keep = [...accounts.slice(0, SWAP_FIXED_ACCOUNTS)];
+  if (groups.wsol) keep.push(...accounts.slice(10, 13));
+  if (groups.bonus) keep.push(...accounts.slice(13, 16));
+  if (groups.bonusLock) keep.push(...accounts.slice(16, 18));
+  if (groups.feeTreasury) keep.push(accounts[18]);
+  if (groups.payer) keep.push(accounts[19]);
+  return { ...ix, accounts: keep };
+}
+
+/** Close with no bonus locks to settle: drop the lock_accs placeholder. */
+export function withoutLockAccounts<T extends Instruction>(ix: T): T {
+  return { ...ix, accounts: (ix.accounts ?? []).filter((meta) => meta.address !== AQUA_SWAP_PROGRAM_ADDRESS) };
+}
@@ -45,6 +45,7 @@ import { getCreateAccountInstruction, getTransferSolInstruction } from '@solana-
 import { getCreateInstruction } from './clients/js/src/generated/instructions/create';
 import { getSwapInstruction } from './clients/js/src/generated/instructions/swap';
 import { AQUA_SWAP_PROGRAM_ADDRESS } from './clients/js/src/generated/programs/aquaSwap';
+import { findConfigPda, findSwapPda, withSwapAccounts } from './aqua-swap-accounts';
 
 const RPC_URL = 'https://api.devnet.solana.com';
 const WS_URL = 'wss://api.devnet.solana.com';
@@ -103,10 +104,8 @@ async function createAquaSwap(config: SwapConfig): Promise<SwapResult> {
     const uuidStr = uuidv4();
     const uuidBytes = uuidParse(uuidStr);
     const uuid = toBigIntLE(Buffer.from(uuidBytes));
-    const [derivedSwapAddress, bumpSeed] = await getProgramDerivedAddress({
-      programAddress: AQUA_SWAP_PROGRAM_ADDRESS,
-      seeds: [uuidBytes],
-    });
+    const [derivedSwapAddress, bumpSeed] = await findSwapPda(payer.address, uuidBytes);
+    const [configAddress] = await findConfigPda();
 
     // Create BASE mint
     const baseMint = await generateKeyPairSigner();
@@ -242,14 +241,20 @@ async function createAquaSwap(config: SwapConfig): Promise<SwapResult> {
       swapAcc: address(derivedSwapAddress),
       vaultBaseAcc: address(baseAta),
       vaultQuoteAcc: address(quoteAta),
-      verifyAcc: payer,
+      configAcc: configAddress,
       createData: {
         uuid,
         price: BigInt(config.price), // Use the config price
         bonusBase: 0n,
         bonusQuote: 0n,
-        bumpSeed: Number(bumpSeed),
-        requireVerify: false
+        bonusBaseMax: 0n,
+        bonusQuoteMax: 0n,
+        bonusLockPeriod: 0n,
+        referrerApproval: 0,
+        createVault: 0,
+        initialDeposit: 0n,
+        priceExponent: 0,
+        bumpSeed: Number(bumpSeed)
       }
     });
 
@@ -347,7 +352,8 @@ async function createAquaSwap(config: SwapConfig): Promise<SwapResult> {
 
     // Execute swap
     const swapAmount = BigInt(config.swapAmount * 10 ** DECIMALS);
-    const swapIx = getSwapInstruction({
+    // SPL-quoted pool without bonuses and no protocol fee: only the fixed accounts
+    const swapIx = withSwapAccounts(getSwapInstruction({
       userAcc: swapUser,
       swapAcc: address(derivedSwapAddress),
       vaultBaseAcc: address(baseAta),
@@ -356,10 +362,11 @@ async function createAquaSwap(config: SwapConfig): Promise<SwapResult> {
       userQuoteAcc: address(swapUserQuoteAta),
       baseMintAcc: address(baseMint.address),
       quoteMintAcc: address(quoteMint.address),
+      configAcc: configAddress,
       swapData: {
         quoteIn: swapAmount
       }
-    });
+    }), {});
 
     const { value: latestBlockhash9 } = await rpc.getLatestBlockhash().send();
     const swapSig = await pipe(
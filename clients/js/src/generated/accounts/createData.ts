@@ -13,6 +13,10 @@ import {
   decodeAccount,
   fetchEncodedAccount,
   fetchEncodedAccounts,
+  getI64Decoder,
+  getI64Encoder,
+  getI8Decoder,
+  getI8Encoder,
   getStructDecoder,
   getStructEncoder,
   getU128Decoder,
@@ -38,6 +42,13 @@ export type CreateData = {
   price: bigint;
   bonusBase: bigint;
   bonusQuote: bigint;
+  bonusBaseMax: bigint;
+  bonusQuoteMax: bigint;
+  bonusLockPeriod: bigint;
+  referrerApproval: number;
+  createVault: number;
+  initialDeposit: bigint;
+  priceExponent: number;
   bumpSeed: number;
 };
 
@@ -46,6 +57,13 @@ export type CreateDataArgs = {
   price: number | bigint;
   bonusBase: number | bigint;
   bonusQuote: number | bigint;
+  bonusBaseMax: number | bigint;
+  bonusQuoteMax: number | bigint;
+  bonusLockPeriod: number | bigint;
+  referrerApproval: number;
+  createVault: number;
+  initialDeposit: number | bigint;
+  priceExponent: number;
   bumpSeed: number;
 };
 
@@ -55,6 +73,13 @@ export function getCreateDataEncoder(): FixedSizeEncoder<CreateDataArgs> {
     ['price', getU64Encoder()],
     ['bonusBase', getU64Encoder()],
     ['bonusQuote', getU64Encoder()],
+    ['bonusBaseMax', getU64Encoder()],
+    ['bonusQuoteMax', getU64Encoder()],
+    ['bonusLockPeriod', getI64Encoder()],
+    ['referrerApproval', getU8Encoder()],
+    ['createVault', getU8Encoder()],
+    ['initialDeposit', getU64Encoder()],
+    ['priceExponent', getI8Encoder()],
     ['bumpSeed', getU8Encoder()],
   ]);
 }
@@ -65,6 +90,13 @@ export function getCreateDataDecoder(): FixedSizeDecoder<CreateData> {
     ['price', getU64Decoder()],
     ['bonusBase', getU64Decoder()],
     ['bonusQuote', getU64Decoder()],
+    ['bonusBaseMax', getU64Decoder()],
+    ['bonusQuoteMax', getU64Decoder()],
+    ['bonusLockPeriod', getI64Decoder()],
+    ['referrerApproval', getU8Decoder()],
+    ['createVault', getU8Decoder()],
+    ['initialDeposit', getU64Decoder()],
+    ['priceExponent', getI8Decoder()],
     ['bumpSeed', getU8Decoder()],
   ]);
 }
@@ -130,5 +162,5 @@ export async function fetchAllMaybeCreateData(
 }
 
 export function getCreateDataSize(): number {
-  return 41;
+  return 76;
 }
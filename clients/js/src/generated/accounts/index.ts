@@ -6,6 +6,28 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './approveReferrerData';
+export * from './batchSwapData';
+export * from './bonusLock';
+export * from './buyOrder';
+export * from './cancelOfferData';
+export * from './config';
+export * from './createBuyOrderData';
 export * from './createData';
+export * from './fillData';
+export * from './market';
+export * from './marketSwapData';
+export * from './postOfferData';
+export * from './quoteData';
+export * from './referrer';
+export * from './rfqNonces';
+export * from './rfqSwapData';
+export * from './routeData';
+export * from './setFeeConfigData';
+export * from './setReferrerRatesData';
+export * from './setRoundingPolicyData';
+export * from './setThrottleData';
+export * from './setVolumeTiersData';
 export * from './swapData';
 export * from './swapState';
+export * from './updateConfigData';
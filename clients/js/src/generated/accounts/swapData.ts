@@ -34,11 +34,15 @@ export type SwapData = { quoteIn: bigint };
 export type SwapDataArgs = { quoteIn: number | bigint };
 
 export function getSwapDataEncoder(): FixedSizeEncoder<SwapDataArgs> {
-  return getStructEncoder([['quoteIn', getU64Encoder()]]);
+  return getStructEncoder([
+    ['quoteIn', getU64Encoder()],
+  ]);
 }
 
 export function getSwapDataDecoder(): FixedSizeDecoder<SwapData> {
-  return getStructDecoder([['quoteIn', getU64Decoder()]]);
+  return getStructDecoder([
+    ['quoteIn', getU64Decoder()],
+  ]);
 }
 
 export function getSwapDataCodec(): FixedSizeCodec<SwapDataArgs, SwapData> {
@@ -15,8 +15,14 @@ import {
   fetchEncodedAccounts,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBooleanDecoder,
   getBooleanEncoder,
+  getI64Decoder,
+  getI64Encoder,
+  getI8Decoder,
+  getI8Encoder,
   getStructDecoder,
   getStructEncoder,
   getU128Decoder,
@@ -36,8 +42,16 @@ import {
   type MaybeAccount,
   type MaybeEncodedAccount,
 } from '@solana/kit';
+import {
+  getVolumeTierDecoder,
+  getVolumeTierEncoder,
+  type VolumeTier,
+  type VolumeTierArgs,
+} from '../types';
 
 export type SwapState = {
+  discriminator: number;
+  version: number;
   owner: Address;
   base: Address;
   quote: Address;
@@ -47,9 +61,31 @@ export type SwapState = {
   bonusQuote: bigint;
   bumpSeed: number;
   quoteSol: boolean;
+  bonusLockPeriod: bigint;
+  bonusLocked: bigint;
+  referrerApproval: boolean;
+  bonusBaseMax: bigint;
+  bonusQuoteMax: bigint;
+  seedScheme: number;
+  totalQuoteReceived: bigint;
+  totalBaseSold: bigint;
+  totalBaseBonusPaid: bigint;
+  totalQuoteBonusPaid: bigint;
+  swapCount: bigint;
+  lastSwapSlot: bigint;
+  volumeTiers: Array<VolumeTier>;
+  rounding: number;
+  minQuoteIn: bigint;
+  priceExponent: number;
+  throttleWindowSlots: bigint;
+  throttleMaxBase: bigint;
+  throttleLastSlot: bigint;
+  throttleBaseSold: bigint;
 };
 
 export type SwapStateArgs = {
+  discriminator: number;
+  version: number;
   owner: Address;
   base: Address;
   quote: Address;
@@ -59,10 +95,32 @@ export type SwapStateArgs = {
   bonusQuote: number | bigint;
   bumpSeed: number;
   quoteSol: boolean;
+  bonusLockPeriod: number | bigint;
+  bonusLocked: number | bigint;
+  referrerApproval: boolean;
+  bonusBaseMax: number | bigint;
+  bonusQuoteMax: number | bigint;
+  seedScheme: number;
+  totalQuoteReceived: number | bigint;
+  totalBaseSold: number | bigint;
+  totalBaseBonusPaid: number | bigint;
+  totalQuoteBonusPaid: number | bigint;
+  swapCount: number | bigint;
+  lastSwapSlot: number | bigint;
+  volumeTiers: Array<VolumeTierArgs>;
+  rounding: number;
+  minQuoteIn: number | bigint;
+  priceExponent: number;
+  throttleWindowSlots: number | bigint;
+  throttleMaxBase: number | bigint;
+  throttleLastSlot: number | bigint;
+  throttleBaseSold: number | bigint;
 };
 
 export function getSwapStateEncoder(): FixedSizeEncoder<SwapStateArgs> {
   return getStructEncoder([
+    ['discriminator', getU8Encoder()],
+    ['version', getU8Encoder()],
     ['owner', getAddressEncoder()],
     ['base', getAddressEncoder()],
     ['quote', getAddressEncoder()],
@@ -72,11 +130,33 @@ export function getSwapStateEncoder(): FixedSizeEncoder<SwapStateArgs> {
     ['bonusQuote', getU64Encoder()],
     ['bumpSeed', getU8Encoder()],
     ['quoteSol', getBooleanEncoder()],
+    ['bonusLockPeriod', getI64Encoder()],
+    ['bonusLocked', getU64Encoder()],
+    ['referrerApproval', getBooleanEncoder()],
+    ['bonusBaseMax', getU64Encoder()],
+    ['bonusQuoteMax', getU64Encoder()],
+    ['seedScheme', getU8Encoder()],
+    ['totalQuoteReceived', getU64Encoder()],
+    ['totalBaseSold', getU64Encoder()],
+    ['totalBaseBonusPaid', getU64Encoder()],
+    ['totalQuoteBonusPaid', getU64Encoder()],
+    ['swapCount', getU64Encoder()],
+    ['lastSwapSlot', getU64Encoder()],
+    ['volumeTiers', getArrayEncoder(getVolumeTierEncoder(), { size: 4 })],
+    ['rounding', getU8Encoder()],
+    ['minQuoteIn', getU64Encoder()],
+    ['priceExponent', getI8Encoder()],
+    ['throttleWindowSlots', getU64Encoder()],
+    ['throttleMaxBase', getU64Encoder()],
+    ['throttleLastSlot', getU64Encoder()],
+    ['throttleBaseSold', getU64Encoder()],
   ]);
 }
 
 export function getSwapStateDecoder(): FixedSizeDecoder<SwapState> {
   return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['version', getU8Decoder()],
     ['owner', getAddressDecoder()],
     ['base', getAddressDecoder()],
     ['quote', getAddressDecoder()],
@@ -86,6 +166,26 @@ export function getSwapStateDecoder(): FixedSizeDecoder<SwapState> {
     ['bonusQuote', getU64Decoder()],
     ['bumpSeed', getU8Decoder()],
     ['quoteSol', getBooleanDecoder()],
+    ['bonusLockPeriod', getI64Decoder()],
+    ['bonusLocked', getU64Decoder()],
+    ['referrerApproval', getBooleanDecoder()],
+    ['bonusBaseMax', getU64Decoder()],
+    ['bonusQuoteMax', getU64Decoder()],
+    ['seedScheme', getU8Decoder()],
+    ['totalQuoteReceived', getU64Decoder()],
+    ['totalBaseSold', getU64Decoder()],
+    ['totalBaseBonusPaid', getU64Decoder()],
+    ['totalQuoteBonusPaid', getU64Decoder()],
+    ['swapCount', getU64Decoder()],
+    ['lastSwapSlot', getU64Decoder()],
+    ['volumeTiers', getArrayDecoder(getVolumeTierDecoder(), { size: 4 })],
+    ['rounding', getU8Decoder()],
+    ['minQuoteIn', getU64Decoder()],
+    ['priceExponent', getI8Decoder()],
+    ['throttleWindowSlots', getU64Decoder()],
+    ['throttleMaxBase', getU64Decoder()],
+    ['throttleLastSlot', getU64Decoder()],
+    ['throttleBaseSold', getU64Decoder()],
   ]);
 }
 
@@ -147,5 +247,5 @@ export async function fetchAllMaybeSwapState(
 }
 
 export function getSwapStateSize(): number {
-  return 138;
+  return 304;
 }
@@ -43,9 +43,11 @@ export type CloseInstruction<
   TAccountSwapAcc extends string | AccountMeta<string> = string,
   TAccountVaultBaseAcc extends string | AccountMeta<string> = string,
   TAccountOwnerBaseAcc extends string | AccountMeta<string> = string,
+  TAccountBaseMintAcc extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends
     | string
     | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountLockAccs extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -64,9 +66,15 @@ export type CloseInstruction<
       TAccountOwnerBaseAcc extends string
         ? WritableAccount<TAccountOwnerBaseAcc>
         : TAccountOwnerBaseAcc,
+      TAccountBaseMintAcc extends string
+        ? ReadonlyAccount<TAccountBaseMintAcc>
+        : TAccountBaseMintAcc,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountLockAccs extends string
+        ? WritableAccount<TAccountLockAccs>
+        : TAccountLockAccs,
       ...TRemainingAccounts,
     ]
   >;
@@ -101,7 +109,9 @@ export type CloseInput<
   TAccountSwapAcc extends string = string,
   TAccountVaultBaseAcc extends string = string,
   TAccountOwnerBaseAcc extends string = string,
+  TAccountBaseMintAcc extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountLockAccs extends string = string,
 > = {
   /** Owner account */
   ownerAcc: TransactionSigner<TAccountOwnerAcc>;
@@ -111,7 +121,11 @@ export type CloseInput<
   vaultBaseAcc: Address<TAccountVaultBaseAcc>;
   /** Owner base token */
   ownerBaseAcc: Address<TAccountOwnerBaseAcc>;
+  /** Base mint */
+  baseMintAcc: Address<TAccountBaseMintAcc>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  /** Outstanding bonus locks, each followed by its recipient base token, settled before closing */
+  lockAccs?: Address<TAccountLockAccs>;
 };
 
 export function getCloseInstruction<
@@ -119,7 +133,9 @@ export function getCloseInstruction<
   TAccountSwapAcc extends string,
   TAccountVaultBaseAcc extends string,
   TAccountOwnerBaseAcc extends string,
+  TAccountBaseMintAcc extends string,
   TAccountTokenProgram extends string,
+  TAccountLockAccs extends string,
   TProgramAddress extends Address = typeof AQUA_SWAP_PROGRAM_ADDRESS,
 >(
   input: CloseInput<
@@ -127,7 +143,9 @@ export function getCloseInstruction<
     TAccountSwapAcc,
     TAccountVaultBaseAcc,
     TAccountOwnerBaseAcc,
-    TAccountTokenProgram
+    TAccountBaseMintAcc,
+    TAccountTokenProgram,
+    TAccountLockAccs
   >,
   config?: { programAddress?: TProgramAddress }
 ): CloseInstruction<
@@ -136,7 +154,9 @@ export function getCloseInstruction<
   TAccountSwapAcc,
   TAccountVaultBaseAcc,
   TAccountOwnerBaseAcc,
-  TAccountTokenProgram
+  TAccountBaseMintAcc,
+  TAccountTokenProgram,
+  TAccountLockAccs
 > {
   // Program address.
   const programAddress = config?.programAddress ?? AQUA_SWAP_PROGRAM_ADDRESS;
@@ -147,7 +167,9 @@ export function getCloseInstruction<
     swapAcc: { value: input.swapAcc ?? null, isWritable: true },
     vaultBaseAcc: { value: input.vaultBaseAcc ?? null, isWritable: true },
     ownerBaseAcc: { value: input.ownerBaseAcc ?? null, isWritable: true },
+    baseMintAcc: { value: input.baseMintAcc ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    lockAccs: { value: input.lockAccs ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -167,7 +189,9 @@ export function getCloseInstruction<
       getAccountMeta(accounts.swapAcc),
       getAccountMeta(accounts.vaultBaseAcc),
       getAccountMeta(accounts.ownerBaseAcc),
+      getAccountMeta(accounts.baseMintAcc),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.lockAccs),
     ],
     data: getCloseInstructionDataEncoder().encode({}),
     programAddress,
@@ -177,7 +201,9 @@ export function getCloseInstruction<
     TAccountSwapAcc,
     TAccountVaultBaseAcc,
     TAccountOwnerBaseAcc,
-    TAccountTokenProgram
+    TAccountBaseMintAcc,
+    TAccountTokenProgram,
+    TAccountLockAccs
   >);
 }
 
@@ -195,7 +221,11 @@ export type ParsedCloseInstruction<
     vaultBaseAcc: TAccountMetas[2];
     /** Owner base token */
     ownerBaseAcc: TAccountMetas[3];
-    tokenProgram: TAccountMetas[4];
+    /** Base mint */
+    baseMintAcc: TAccountMetas[4];
+    tokenProgram: TAccountMetas[5];
+    /** Outstanding bonus locks, each followed by its recipient base token, settled before closing */
+    lockAccs?: TAccountMetas[6] | undefined;
   };
   data: CloseInstructionData;
 };
@@ -208,7 +238,7 @@ export function parseCloseInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCloseInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -218,6 +248,12 @@ export function parseCloseInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === AQUA_SWAP_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -225,7 +261,9 @@ export function parseCloseInstruction<
       swapAcc: getNextAccount(),
       vaultBaseAcc: getNextAccount(),
       ownerBaseAcc: getNextAccount(),
+      baseMintAcc: getNextAccount(),
       tokenProgram: getNextAccount(),
+      lockAccs: getNextOptionalAccount(),
     },
     data: getCloseInstructionDataDecoder().decode(instruction.data),
   };
@@ -55,6 +55,15 @@ export type CreateInstruction<
   TAccountRent extends
     | string
     | AccountMeta<string> = 'SysvarRent111111111111111111111111111111111',
+  TAccountConfigAcc extends string | AccountMeta<string> = string,
+  TAccountBaseMintAcc extends string | AccountMeta<string> = string,
+  TAccountOwnerBaseAcc extends string | AccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountAtaProgram extends
+    | string
+    | AccountMeta<string> = 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -68,7 +77,7 @@ export type CreateInstruction<
         ? WritableAccount<TAccountSwapAcc>
         : TAccountSwapAcc,
       TAccountVaultBaseAcc extends string
-        ? ReadonlyAccount<TAccountVaultBaseAcc>
+        ? WritableAccount<TAccountVaultBaseAcc>
         : TAccountVaultBaseAcc,
       TAccountVaultQuoteAcc extends string
         ? ReadonlyAccount<TAccountVaultQuoteAcc>
@@ -79,6 +88,21 @@ export type CreateInstruction<
       TAccountRent extends string
         ? ReadonlyAccount<TAccountRent>
         : TAccountRent,
+      TAccountConfigAcc extends string
+        ? ReadonlyAccount<TAccountConfigAcc>
+        : TAccountConfigAcc,
+      TAccountBaseMintAcc extends string
+        ? ReadonlyAccount<TAccountBaseMintAcc>
+        : TAccountBaseMintAcc,
+      TAccountOwnerBaseAcc extends string
+        ? WritableAccount<TAccountOwnerBaseAcc>
+        : TAccountOwnerBaseAcc,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountAtaProgram extends string
+        ? ReadonlyAccount<TAccountAtaProgram>
+        : TAccountAtaProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -124,6 +148,11 @@ export type CreateInput<
   TAccountVaultQuoteAcc extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountRent extends string = string,
+  TAccountConfigAcc extends string = string,
+  TAccountBaseMintAcc extends string = string,
+  TAccountOwnerBaseAcc extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountAtaProgram extends string = string,
 > = {
   /** Owner account */
   ownerAcc: TransactionSigner<TAccountOwnerAcc>;
@@ -135,6 +164,14 @@ export type CreateInput<
   vaultQuoteAcc: Address<TAccountVaultQuoteAcc>;
   systemProgram?: Address<TAccountSystemProgram>;
   rent?: Address<TAccountRent>;
+  /** Program config */
+  configAcc: Address<TAccountConfigAcc>;
+  /** Base mint (with create_vault or initial_deposit) */
+  baseMintAcc?: Address<TAccountBaseMintAcc>;
+  /** Owner base token funding initial_deposit */
+  ownerBaseAcc?: Address<TAccountOwnerBaseAcc>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  ataProgram?: Address<TAccountAtaProgram>;
   createData: CreateInstructionDataArgs['createData'];
 };
 
@@ -145,6 +182,11 @@ export function getCreateInstruction<
   TAccountVaultQuoteAcc extends string,
   TAccountSystemProgram extends string,
   TAccountRent extends string,
+  TAccountConfigAcc extends string,
+  TAccountBaseMintAcc extends string,
+  TAccountOwnerBaseAcc extends string,
+  TAccountTokenProgram extends string,
+  TAccountAtaProgram extends string,
   TProgramAddress extends Address = typeof AQUA_SWAP_PROGRAM_ADDRESS,
 >(
   input: CreateInput<
@@ -153,7 +195,12 @@ export function getCreateInstruction<
     TAccountVaultBaseAcc,
     TAccountVaultQuoteAcc,
     TAccountSystemProgram,
-    TAccountRent
+    TAccountRent,
+    TAccountConfigAcc,
+    TAccountBaseMintAcc,
+    TAccountOwnerBaseAcc,
+    TAccountTokenProgram,
+    TAccountAtaProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): CreateInstruction<
@@ -163,7 +210,12 @@ export function getCreateInstruction<
   TAccountVaultBaseAcc,
   TAccountVaultQuoteAcc,
   TAccountSystemProgram,
-  TAccountRent
+  TAccountRent,
+  TAccountConfigAcc,
+  TAccountBaseMintAcc,
+  TAccountOwnerBaseAcc,
+  TAccountTokenProgram,
+  TAccountAtaProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? AQUA_SWAP_PROGRAM_ADDRESS;
@@ -172,10 +224,15 @@ export function getCreateInstruction<
   const originalAccounts = {
     ownerAcc: { value: input.ownerAcc ?? null, isWritable: true },
     swapAcc: { value: input.swapAcc ?? null, isWritable: true },
-    vaultBaseAcc: { value: input.vaultBaseAcc ?? null, isWritable: false },
+    vaultBaseAcc: { value: input.vaultBaseAcc ?? null, isWritable: true },
     vaultQuoteAcc: { value: input.vaultQuoteAcc ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     rent: { value: input.rent ?? null, isWritable: false },
+    configAcc: { value: input.configAcc ?? null, isWritable: false },
+    baseMintAcc: { value: input.baseMintAcc ?? null, isWritable: false },
+    ownerBaseAcc: { value: input.ownerBaseAcc ?? null, isWritable: true },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    ataProgram: { value: input.ataProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -194,6 +251,14 @@ export function getCreateInstruction<
     accounts.rent.value =
       'SysvarRent111111111111111111111111111111111' as Address<'SysvarRent111111111111111111111111111111111'>;
   }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+  if (!accounts.ataProgram.value) {
+    accounts.ataProgram.value =
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -204,6 +269,11 @@ export function getCreateInstruction<
       getAccountMeta(accounts.vaultQuoteAcc),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.rent),
+      getAccountMeta(accounts.configAcc),
+      getAccountMeta(accounts.baseMintAcc),
+      getAccountMeta(accounts.ownerBaseAcc),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.ataProgram),
     ],
     data: getCreateInstructionDataEncoder().encode(
       args as CreateInstructionDataArgs
@@ -216,7 +286,12 @@ export function getCreateInstruction<
     TAccountVaultBaseAcc,
     TAccountVaultQuoteAcc,
     TAccountSystemProgram,
-    TAccountRent
+    TAccountRent,
+    TAccountConfigAcc,
+    TAccountBaseMintAcc,
+    TAccountOwnerBaseAcc,
+    TAccountTokenProgram,
+    TAccountAtaProgram
   >);
 }
 
@@ -236,6 +311,14 @@ export type ParsedCreateInstruction<
     vaultQuoteAcc: TAccountMetas[3];
     systemProgram: TAccountMetas[4];
     rent: TAccountMetas[5];
+    /** Program config */
+    configAcc: TAccountMetas[6];
+    /** Base mint (with create_vault or initial_deposit) */
+    baseMintAcc?: TAccountMetas[7] | undefined;
+    /** Owner base token funding initial_deposit */
+    ownerBaseAcc?: TAccountMetas[8] | undefined;
+    tokenProgram?: TAccountMetas[9] | undefined;
+    ataProgram?: TAccountMetas[10] | undefined;
   };
   data: CreateInstructionData;
 };
@@ -248,7 +331,7 @@ export function parseCreateInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCreateInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -258,6 +341,12 @@ export function parseCreateInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === AQUA_SWAP_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -267,6 +356,11 @@ export function parseCreateInstruction<
       vaultQuoteAcc: getNextAccount(),
       systemProgram: getNextAccount(),
       rent: getNextAccount(),
+      configAcc: getNextAccount(),
+      baseMintAcc: getNextOptionalAccount(),
+      ownerBaseAcc: getNextOptionalAccount(),
+      tokenProgram: getNextOptionalAccount(),
+      ataProgram: getNextOptionalAccount(),
     },
     data: getCreateInstructionDataDecoder().decode(instruction.data),
   };
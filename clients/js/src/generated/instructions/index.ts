@@ -6,6 +6,28 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './approveReferrer';
+export * from './batchSwap';
+export * from './cancelBuyOrder';
+export * from './cancelOffer';
+export * from './claimBonus';
 export * from './close';
 export * from './create';
+export * from './createBuyOrder';
+export * from './createMarket';
+export * from './fill';
+export * from './initConfig';
+export * from './marketSwap';
+export * from './migrateSwap';
+export * from './postOffer';
+export * from './quote';
+export * from './registerReferrer';
+export * from './rfqSwap';
+export * from './route';
+export * from './setFeeConfig';
+export * from './setReferrerRates';
+export * from './setRoundingPolicy';
+export * from './setThrottle';
+export * from './setVolumeTiers';
 export * from './swap';
+export * from './updateConfig';
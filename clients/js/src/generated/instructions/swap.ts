@@ -23,6 +23,7 @@ import {
   type InstructionWithAccounts,
   type InstructionWithData,
   type ReadonlyAccount,
+  type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -53,29 +54,35 @@ export type SwapInstruction<
   TAccountUserQuoteAcc extends string | AccountMeta<string> = string,
   TAccountBaseMintAcc extends string | AccountMeta<string> = string,
   TAccountQuoteMintAcc extends string | AccountMeta<string> = string,
-  TAccountBonusBaseAcc extends string | AccountMeta<string> = string,
-  TAccountBonusQuoteAcc extends string | AccountMeta<string> = string,
-  TAccountWsolTempAcc extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends
     | string
     | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountConfigAcc extends string | AccountMeta<string> = string,
+  TAccountWsolTempAcc extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
   TAccountAtaProgram extends
     | string
     | AccountMeta<string> = 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
+  TAccountBonusBaseAcc extends string | AccountMeta<string> = string,
+  TAccountBonusQuoteAcc extends string | AccountMeta<string> = string,
+  TAccountReferrerAcc extends string | AccountMeta<string> = string,
+  TAccountBonusLockAcc extends string | AccountMeta<string> = string,
+  TAccountLockSystemProgram extends string | AccountMeta<string> = string,
+  TAccountFeeTreasuryAcc extends string | AccountMeta<string> = string,
+  TAccountPayerAcc extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
   InstructionWithAccounts<
     [
       TAccountUserAcc extends string
-        ? WritableSignerAccount<TAccountUserAcc> &
+        ? ReadonlySignerAccount<TAccountUserAcc> &
             AccountSignerMeta<TAccountUserAcc>
         : TAccountUserAcc,
       TAccountSwapAcc extends string
-        ? ReadonlyAccount<TAccountSwapAcc>
+        ? WritableAccount<TAccountSwapAcc>
         : TAccountSwapAcc,
       TAccountVaultBaseAcc extends string
         ? WritableAccount<TAccountVaultBaseAcc>
@@ -95,24 +102,43 @@ export type SwapInstruction<
       TAccountQuoteMintAcc extends string
         ? ReadonlyAccount<TAccountQuoteMintAcc>
         : TAccountQuoteMintAcc,
-      TAccountBonusBaseAcc extends string
-        ? WritableAccount<TAccountBonusBaseAcc>
-        : TAccountBonusBaseAcc,
-      TAccountBonusQuoteAcc extends string
-        ? WritableAccount<TAccountBonusQuoteAcc>
-        : TAccountBonusQuoteAcc,
-      TAccountWsolTempAcc extends string
-        ? WritableAccount<TAccountWsolTempAcc>
-        : TAccountWsolTempAcc,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountConfigAcc extends string
+        ? ReadonlyAccount<TAccountConfigAcc>
+        : TAccountConfigAcc,
+      TAccountWsolTempAcc extends string
+        ? WritableAccount<TAccountWsolTempAcc>
+        : TAccountWsolTempAcc,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
       TAccountAtaProgram extends string
         ? ReadonlyAccount<TAccountAtaProgram>
         : TAccountAtaProgram,
+      TAccountBonusBaseAcc extends string
+        ? WritableAccount<TAccountBonusBaseAcc>
+        : TAccountBonusBaseAcc,
+      TAccountBonusQuoteAcc extends string
+        ? WritableAccount<TAccountBonusQuoteAcc>
+        : TAccountBonusQuoteAcc,
+      TAccountReferrerAcc extends string
+        ? ReadonlyAccount<TAccountReferrerAcc>
+        : TAccountReferrerAcc,
+      TAccountBonusLockAcc extends string
+        ? WritableAccount<TAccountBonusLockAcc>
+        : TAccountBonusLockAcc,
+      TAccountLockSystemProgram extends string
+        ? ReadonlyAccount<TAccountLockSystemProgram>
+        : TAccountLockSystemProgram,
+      TAccountFeeTreasuryAcc extends string
+        ? WritableAccount<TAccountFeeTreasuryAcc>
+        : TAccountFeeTreasuryAcc,
+      TAccountPayerAcc extends string
+        ? WritableSignerAccount<TAccountPayerAcc> &
+            AccountSignerMeta<TAccountPayerAcc>
+        : TAccountPayerAcc,
       ...TRemainingAccounts,
     ]
   >;
@@ -157,14 +183,20 @@ export type SwapInput<
   TAccountUserQuoteAcc extends string = string,
   TAccountBaseMintAcc extends string = string,
   TAccountQuoteMintAcc extends string = string,
-  TAccountBonusBaseAcc extends string = string,
-  TAccountBonusQuoteAcc extends string = string,
-  TAccountWsolTempAcc extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountConfigAcc extends string = string,
+  TAccountWsolTempAcc extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountAtaProgram extends string = string,
+  TAccountBonusBaseAcc extends string = string,
+  TAccountBonusQuoteAcc extends string = string,
+  TAccountReferrerAcc extends string = string,
+  TAccountBonusLockAcc extends string = string,
+  TAccountLockSystemProgram extends string = string,
+  TAccountFeeTreasuryAcc extends string = string,
+  TAccountPayerAcc extends string = string,
 > = {
-  /** User account */
+  /** Authority over user_quote_acc (owner or delegate, may be a PDA) */
   userAcc: TransactionSigner<TAccountUserAcc>;
   /** Swap account */
   swapAcc: Address<TAccountSwapAcc>;
@@ -172,7 +204,7 @@ export type SwapInput<
   vaultBaseAcc: Address<TAccountVaultBaseAcc>;
   /** Quote vault */
   vaultQuoteAcc: Address<TAccountVaultQuoteAcc>;
-  /** User base token */
+  /** Base token account receiving base_out (any owner) */
   userBaseAcc: Address<TAccountUserBaseAcc>;
   /** User quote token */
   userQuoteAcc: Address<TAccountUserQuoteAcc>;
@@ -180,15 +212,29 @@ export type SwapInput<
   baseMintAcc: Address<TAccountBaseMintAcc>;
   /** Quote mint */
   quoteMintAcc: Address<TAccountQuoteMintAcc>;
-  /** Bonus base token */
-  bonusBaseAcc: Address<TAccountBonusBaseAcc>;
-  /** Bonus quote token or account */
-  bonusQuoteAcc: Address<TAccountBonusQuoteAcc>;
-  /** WSOL temporary token */
-  wsolTempAcc: Address<TAccountWsolTempAcc>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  /** Program config */
+  configAcc: Address<TAccountConfigAcc>;
+  /** WSOL temporary token (SOL-quoted pools only) */
+  wsolTempAcc?: Address<TAccountWsolTempAcc>;
+  /** SOL-quoted pools only */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** SOL-quoted pools only */
   ataProgram?: Address<TAccountAtaProgram>;
+  /** Bonus base token (pools with bonuses only) */
+  bonusBaseAcc?: Address<TAccountBonusBaseAcc>;
+  /** Bonus quote token or account (pools with bonuses only) */
+  bonusQuoteAcc?: Address<TAccountBonusQuoteAcc>;
+  /** Referrer registration of the bonus recipient (pools with bonuses only) */
+  referrerAcc?: Address<TAccountReferrerAcc>;
+  /** Bonus lock for bonus_base_acc (pools locking base bonuses only) */
+  bonusLockAcc?: Address<TAccountBonusLockAcc>;
+  /** Pools locking base bonuses only */
+  lockSystemProgram?: Address<TAccountLockSystemProgram>;
+  /** Fee treasury, quote token account or wallet for SOL (when fee_bps is set) */
+  feeTreasuryAcc?: Address<TAccountFeeTreasuryAcc>;
+  /** System account paying rent and relaying SOL (defaults to user_acc) */
+  payerAcc?: TransactionSigner<TAccountPayerAcc>;
   swapData: SwapInstructionDataArgs['swapData'];
 };
 
@@ -201,12 +247,18 @@ export function getSwapInstruction<
   TAccountUserQuoteAcc extends string,
   TAccountBaseMintAcc extends string,
   TAccountQuoteMintAcc extends string,
-  TAccountBonusBaseAcc extends string,
-  TAccountBonusQuoteAcc extends string,
-  TAccountWsolTempAcc extends string,
   TAccountTokenProgram extends string,
+  TAccountConfigAcc extends string,
+  TAccountWsolTempAcc extends string,
   TAccountSystemProgram extends string,
   TAccountAtaProgram extends string,
+  TAccountBonusBaseAcc extends string,
+  TAccountBonusQuoteAcc extends string,
+  TAccountReferrerAcc extends string,
+  TAccountBonusLockAcc extends string,
+  TAccountLockSystemProgram extends string,
+  TAccountFeeTreasuryAcc extends string,
+  TAccountPayerAcc extends string,
   TProgramAddress extends Address = typeof AQUA_SWAP_PROGRAM_ADDRESS,
 >(
   input: SwapInput<
@@ -218,12 +270,18 @@ export function getSwapInstruction<
     TAccountUserQuoteAcc,
     TAccountBaseMintAcc,
     TAccountQuoteMintAcc,
-    TAccountBonusBaseAcc,
-    TAccountBonusQuoteAcc,
-    TAccountWsolTempAcc,
     TAccountTokenProgram,
+    TAccountConfigAcc,
+    TAccountWsolTempAcc,
     TAccountSystemProgram,
-    TAccountAtaProgram
+    TAccountAtaProgram,
+    TAccountBonusBaseAcc,
+    TAccountBonusQuoteAcc,
+    TAccountReferrerAcc,
+    TAccountBonusLockAcc,
+    TAccountLockSystemProgram,
+    TAccountFeeTreasuryAcc,
+    TAccountPayerAcc
   >,
   config?: { programAddress?: TProgramAddress }
 ): SwapInstruction<
@@ -236,32 +294,47 @@ export function getSwapInstruction<
   TAccountUserQuoteAcc,
   TAccountBaseMintAcc,
   TAccountQuoteMintAcc,
-  TAccountBonusBaseAcc,
-  TAccountBonusQuoteAcc,
-  TAccountWsolTempAcc,
   TAccountTokenProgram,
+  TAccountConfigAcc,
+  TAccountWsolTempAcc,
   TAccountSystemProgram,
-  TAccountAtaProgram
+  TAccountAtaProgram,
+  TAccountBonusBaseAcc,
+  TAccountBonusQuoteAcc,
+  TAccountReferrerAcc,
+  TAccountBonusLockAcc,
+  TAccountLockSystemProgram,
+  TAccountFeeTreasuryAcc,
+  TAccountPayerAcc
 > {
   // Program address.
   const programAddress = config?.programAddress ?? AQUA_SWAP_PROGRAM_ADDRESS;
 
   // Original accounts.
   const originalAccounts = {
-    userAcc: { value: input.userAcc ?? null, isWritable: true },
-    swapAcc: { value: input.swapAcc ?? null, isWritable: false },
+    userAcc: { value: input.userAcc ?? null, isWritable: false },
+    swapAcc: { value: input.swapAcc ?? null, isWritable: true },
     vaultBaseAcc: { value: input.vaultBaseAcc ?? null, isWritable: true },
     vaultQuoteAcc: { value: input.vaultQuoteAcc ?? null, isWritable: true },
     userBaseAcc: { value: input.userBaseAcc ?? null, isWritable: true },
     userQuoteAcc: { value: input.userQuoteAcc ?? null, isWritable: true },
     baseMintAcc: { value: input.baseMintAcc ?? null, isWritable: false },
     quoteMintAcc: { value: input.quoteMintAcc ?? null, isWritable: false },
-    bonusBaseAcc: { value: input.bonusBaseAcc ?? null, isWritable: true },
-    bonusQuoteAcc: { value: input.bonusQuoteAcc ?? null, isWritable: true },
-    wsolTempAcc: { value: input.wsolTempAcc ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    configAcc: { value: input.configAcc ?? null, isWritable: false },
+    wsolTempAcc: { value: input.wsolTempAcc ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     ataProgram: { value: input.ataProgram ?? null, isWritable: false },
+    bonusBaseAcc: { value: input.bonusBaseAcc ?? null, isWritable: true },
+    bonusQuoteAcc: { value: input.bonusQuoteAcc ?? null, isWritable: true },
+    referrerAcc: { value: input.referrerAcc ?? null, isWritable: false },
+    bonusLockAcc: { value: input.bonusLockAcc ?? null, isWritable: true },
+    lockSystemProgram: {
+      value: input.lockSystemProgram ?? null,
+      isWritable: false,
+    },
+    feeTreasuryAcc: { value: input.feeTreasuryAcc ?? null, isWritable: true },
+    payerAcc: { value: input.payerAcc ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -296,12 +369,18 @@ export function getSwapInstruction<
       getAccountMeta(accounts.userQuoteAcc),
       getAccountMeta(accounts.baseMintAcc),
       getAccountMeta(accounts.quoteMintAcc),
-      getAccountMeta(accounts.bonusBaseAcc),
-      getAccountMeta(accounts.bonusQuoteAcc),
-      getAccountMeta(accounts.wsolTempAcc),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.configAcc),
+      getAccountMeta(accounts.wsolTempAcc),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.ataProgram),
+      getAccountMeta(accounts.bonusBaseAcc),
+      getAccountMeta(accounts.bonusQuoteAcc),
+      getAccountMeta(accounts.referrerAcc),
+      getAccountMeta(accounts.bonusLockAcc),
+      getAccountMeta(accounts.lockSystemProgram),
+      getAccountMeta(accounts.feeTreasuryAcc),
+      getAccountMeta(accounts.payerAcc),
     ],
     data: getSwapInstructionDataEncoder().encode(
       args as SwapInstructionDataArgs
@@ -317,12 +396,18 @@ export function getSwapInstruction<
     TAccountUserQuoteAcc,
     TAccountBaseMintAcc,
     TAccountQuoteMintAcc,
-    TAccountBonusBaseAcc,
-    TAccountBonusQuoteAcc,
-    TAccountWsolTempAcc,
     TAccountTokenProgram,
+    TAccountConfigAcc,
+    TAccountWsolTempAcc,
     TAccountSystemProgram,
-    TAccountAtaProgram
+    TAccountAtaProgram,
+    TAccountBonusBaseAcc,
+    TAccountBonusQuoteAcc,
+    TAccountReferrerAcc,
+    TAccountBonusLockAcc,
+    TAccountLockSystemProgram,
+    TAccountFeeTreasuryAcc,
+    TAccountPayerAcc
   >);
 }
 
@@ -332,7 +417,7 @@ export type ParsedSwapInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    /** User account */
+    /** Authority over user_quote_acc (owner or delegate, may be a PDA) */
     userAcc: TAccountMetas[0];
     /** Swap account */
     swapAcc: TAccountMetas[1];
@@ -340,7 +425,7 @@ export type ParsedSwapInstruction<
     vaultBaseAcc: TAccountMetas[2];
     /** Quote vault */
     vaultQuoteAcc: TAccountMetas[3];
-    /** User base token */
+    /** Base token account receiving base_out (any owner) */
     userBaseAcc: TAccountMetas[4];
     /** User quote token */
     userQuoteAcc: TAccountMetas[5];
@@ -348,15 +433,29 @@ export type ParsedSwapInstruction<
     baseMintAcc: TAccountMetas[6];
     /** Quote mint */
     quoteMintAcc: TAccountMetas[7];
-    /** Bonus base token */
-    bonusBaseAcc: TAccountMetas[8];
-    /** Bonus quote token or account */
-    bonusQuoteAcc: TAccountMetas[9];
-    /** WSOL temporary token */
-    wsolTempAcc: TAccountMetas[10];
-    tokenProgram: TAccountMetas[11];
-    systemProgram: TAccountMetas[12];
-    ataProgram: TAccountMetas[13];
+    tokenProgram: TAccountMetas[8];
+    /** Program config */
+    configAcc: TAccountMetas[9];
+    /** WSOL temporary token (SOL-quoted pools only) */
+    wsolTempAcc?: TAccountMetas[10] | undefined;
+    /** SOL-quoted pools only */
+    systemProgram?: TAccountMetas[11] | undefined;
+    /** SOL-quoted pools only */
+    ataProgram?: TAccountMetas[12] | undefined;
+    /** Bonus base token (pools with bonuses only) */
+    bonusBaseAcc?: TAccountMetas[13] | undefined;
+    /** Bonus quote token or account (pools with bonuses only) */
+    bonusQuoteAcc?: TAccountMetas[14] | undefined;
+    /** Referrer registration of the bonus recipient (pools with bonuses only) */
+    referrerAcc?: TAccountMetas[15] | undefined;
+    /** Bonus lock for bonus_base_acc (pools locking base bonuses only) */
+    bonusLockAcc?: TAccountMetas[16] | undefined;
+    /** Pools locking base bonuses only */
+    lockSystemProgram?: TAccountMetas[17] | undefined;
+    /** Fee treasury, quote token account or wallet for SOL (when fee_bps is set) */
+    feeTreasuryAcc?: TAccountMetas[18] | undefined;
+    /** System account paying rent and relaying SOL (defaults to user_acc) */
+    payerAcc?: TAccountMetas[19] | undefined;
   };
   data: SwapInstructionData;
 };
@@ -369,7 +468,7 @@ export function parseSwapInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSwapInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 14) {
+  if (instruction.accounts.length < 20) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -379,6 +478,12 @@ export function parseSwapInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === AQUA_SWAP_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -390,12 +495,18 @@ export function parseSwapInstruction<
       userQuoteAcc: getNextAccount(),
       baseMintAcc: getNextAccount(),
       quoteMintAcc: getNextAccount(),
-      bonusBaseAcc: getNextAccount(),
-      bonusQuoteAcc: getNextAccount(),
-      wsolTempAcc: getNextAccount(),
       tokenProgram: getNextAccount(),
-      systemProgram: getNextAccount(),
-      ataProgram: getNextAccount(),
+      configAcc: getNextAccount(),
+      wsolTempAcc: getNextOptionalAccount(),
+      systemProgram: getNextOptionalAccount(),
+      ataProgram: getNextOptionalAccount(),
+      bonusBaseAcc: getNextOptionalAccount(),
+      bonusQuoteAcc: getNextOptionalAccount(),
+      referrerAcc: getNextOptionalAccount(),
+      bonusLockAcc: getNextOptionalAccount(),
+      lockSystemProgram: getNextOptionalAccount(),
+      feeTreasuryAcc: getNextOptionalAccount(),
+      payerAcc: getNextOptionalAccount(),
     },
     data: getSwapInstructionDataDecoder().decode(instruction.data),
   };
@@ -13,17 +13,61 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedApproveReferrerInstruction,
+  type ParsedBatchSwapInstruction,
+  type ParsedCancelBuyOrderInstruction,
+  type ParsedCancelOfferInstruction,
+  type ParsedClaimBonusInstruction,
   type ParsedCloseInstruction,
+  type ParsedCreateBuyOrderInstruction,
   type ParsedCreateInstruction,
+  type ParsedCreateMarketInstruction,
+  type ParsedFillInstruction,
+  type ParsedInitConfigInstruction,
+  type ParsedMarketSwapInstruction,
+  type ParsedMigrateSwapInstruction,
+  type ParsedPostOfferInstruction,
+  type ParsedQuoteInstruction,
+  type ParsedRegisterReferrerInstruction,
+  type ParsedRfqSwapInstruction,
+  type ParsedRouteInstruction,
+  type ParsedSetFeeConfigInstruction,
+  type ParsedSetReferrerRatesInstruction,
+  type ParsedSetRoundingPolicyInstruction,
+  type ParsedSetThrottleInstruction,
+  type ParsedSetVolumeTiersInstruction,
   type ParsedSwapInstruction,
+  type ParsedUpdateConfigInstruction,
 } from '../instructions';
 
 export const AQUA_SWAP_PROGRAM_ADDRESS =
   'SWAPmcsgGvfZMoHjp9wSMnGk5S2nVHxCwYAGfta9Vyp' as Address<'SWAPmcsgGvfZMoHjp9wSMnGk5S2nVHxCwYAGfta9Vyp'>;
 
 export enum AquaSwapAccount {
+  ApproveReferrerData,
+  BatchSwapData,
+  CancelOfferData,
   CreateData,
+  CreateBuyOrderData,
+  FillData,
+  MarketSwapData,
+  PostOfferData,
+  QuoteData,
+  RfqSwapData,
+  RouteData,
+  SetFeeConfigData,
+  SetReferrerRatesData,
+  SetRoundingPolicyData,
+  SetThrottleData,
+  SetVolumeTiersData,
   SwapData,
+  UpdateConfigData,
+  BonusLock,
+  BuyOrder,
+  Config,
+  Market,
+  Referrer,
+  RfqNonces,
   SwapState,
 }
 
@@ -31,6 +75,28 @@ export enum AquaSwapInstruction {
   Create,
   Swap,
   Close,
+  ClaimBonus,
+  RegisterReferrer,
+  ApproveReferrer,
+  SetReferrerRates,
+  InitConfig,
+  SetFeeConfig,
+  UpdateConfig,
+  MigrateSwap,
+  Quote,
+  BatchSwap,
+  Route,
+  RfqSwap,
+  CreateMarket,
+  PostOffer,
+  CancelOffer,
+  MarketSwap,
+  CreateBuyOrder,
+  Fill,
+  CancelBuyOrder,
+  SetVolumeTiers,
+  SetRoundingPolicy,
+  SetThrottle,
 }
 
 export function identifyAquaSwapInstruction(
@@ -46,6 +112,72 @@ export function identifyAquaSwapInstruction(
   if (containsBytes(data, getU8Encoder().encode(2), 0)) {
     return AquaSwapInstruction.Close;
   }
+  if (containsBytes(data, getU8Encoder().encode(3), 0)) {
+    return AquaSwapInstruction.ClaimBonus;
+  }
+  if (containsBytes(data, getU8Encoder().encode(4), 0)) {
+    return AquaSwapInstruction.RegisterReferrer;
+  }
+  if (containsBytes(data, getU8Encoder().encode(5), 0)) {
+    return AquaSwapInstruction.ApproveReferrer;
+  }
+  if (containsBytes(data, getU8Encoder().encode(6), 0)) {
+    return AquaSwapInstruction.SetReferrerRates;
+  }
+  if (containsBytes(data, getU8Encoder().encode(7), 0)) {
+    return AquaSwapInstruction.InitConfig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(8), 0)) {
+    return AquaSwapInstruction.SetFeeConfig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(9), 0)) {
+    return AquaSwapInstruction.UpdateConfig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(10), 0)) {
+    return AquaSwapInstruction.MigrateSwap;
+  }
+  if (containsBytes(data, getU8Encoder().encode(11), 0)) {
+    return AquaSwapInstruction.Quote;
+  }
+  if (containsBytes(data, getU8Encoder().encode(12), 0)) {
+    return AquaSwapInstruction.BatchSwap;
+  }
+  if (containsBytes(data, getU8Encoder().encode(13), 0)) {
+    return AquaSwapInstruction.Route;
+  }
+  if (containsBytes(data, getU8Encoder().encode(14), 0)) {
+    return AquaSwapInstruction.RfqSwap;
+  }
+  if (containsBytes(data, getU8Encoder().encode(15), 0)) {
+    return AquaSwapInstruction.CreateMarket;
+  }
+  if (containsBytes(data, getU8Encoder().encode(16), 0)) {
+    return AquaSwapInstruction.PostOffer;
+  }
+  if (containsBytes(data, getU8Encoder().encode(17), 0)) {
+    return AquaSwapInstruction.CancelOffer;
+  }
+  if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+    return AquaSwapInstruction.MarketSwap;
+  }
+  if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+    return AquaSwapInstruction.CreateBuyOrder;
+  }
+  if (containsBytes(data, getU8Encoder().encode(20), 0)) {
+    return AquaSwapInstruction.Fill;
+  }
+  if (containsBytes(data, getU8Encoder().encode(21), 0)) {
+    return AquaSwapInstruction.CancelBuyOrder;
+  }
+  if (containsBytes(data, getU8Encoder().encode(22), 0)) {
+    return AquaSwapInstruction.SetVolumeTiers;
+  }
+  if (containsBytes(data, getU8Encoder().encode(23), 0)) {
+    return AquaSwapInstruction.SetRoundingPolicy;
+  }
+  if (containsBytes(data, getU8Encoder().encode(24), 0)) {
+    return AquaSwapInstruction.SetThrottle;
+  }
   throw new Error(
     'The provided instruction could not be identified as a aquaSwap instruction.'
   );
@@ -62,4 +194,70 @@ export type ParsedAquaSwapInstruction<
     } & ParsedSwapInstruction<TProgram>)
   | ({
       instructionType: AquaSwapInstruction.Close;
-    } & ParsedCloseInstruction<TProgram>);
+    } & ParsedCloseInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.ClaimBonus;
+    } & ParsedClaimBonusInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.RegisterReferrer;
+    } & ParsedRegisterReferrerInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.ApproveReferrer;
+    } & ParsedApproveReferrerInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.SetReferrerRates;
+    } & ParsedSetReferrerRatesInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.InitConfig;
+    } & ParsedInitConfigInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.SetFeeConfig;
+    } & ParsedSetFeeConfigInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.UpdateConfig;
+    } & ParsedUpdateConfigInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.MigrateSwap;
+    } & ParsedMigrateSwapInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.Quote;
+    } & ParsedQuoteInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.BatchSwap;
+    } & ParsedBatchSwapInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.Route;
+    } & ParsedRouteInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.RfqSwap;
+    } & ParsedRfqSwapInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.CreateMarket;
+    } & ParsedCreateMarketInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.PostOffer;
+    } & ParsedPostOfferInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.CancelOffer;
+    } & ParsedCancelOfferInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.MarketSwap;
+    } & ParsedMarketSwapInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.CreateBuyOrder;
+    } & ParsedCreateBuyOrderInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.Fill;
+    } & ParsedFillInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.CancelBuyOrder;
+    } & ParsedCancelBuyOrderInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.SetVolumeTiers;
+    } & ParsedSetVolumeTiersInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.SetRoundingPolicy;
+    } & ParsedSetRoundingPolicyInstruction<TProgram>)
+  | ({
+      instructionType: AquaSwapInstruction.SetThrottle;
+    } & ParsedSetThrottleInstruction<TProgram>);
@@ -6,4 +6,29 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './bonusClaimed';
+export * from './buyOrderCancelled';
+export * from './buyOrderCreated';
+export * from './buyOrderFilled';
+export * from './configUpdated';
+export * from './eventDiscriminator';
+export * from './lockTranche';
+export * from './offer';
+export * from './offerCancelled';
+export * from './offerFilled';
+export * from './offerPosted';
+export * from './quoteResult';
+export * from './referrerRegistered';
+export * from './referrerUpdated';
+export * from './rfqQuote';
+export * from './rounding';
+export * from './roundingPolicyUpdated';
+export * from './swapClosed';
+export * from './swapCreated';
 export * from './swapError';
+export * from './swapMigrated';
+export * from './swapResult';
+export * from './swapped';
+export * from './throttleUpdated';
+export * from './volumeTier';
+export * from './volumeTiersUpdated';
@@ -62,6 +62,214 @@ export enum SwapError {
   WrongVaultQuoteSwap,
   SameMintCreate,
   NotOwnerClose,
+  InvalidParametersCreateBonusLockPeriod,
+  InvalidParametersBonusLockedOverflow,
+  InsufficientVaultBaseSwap,
+  WrongBonusLockSwap,
+  NotEnoughAccountKeysClaimBonus,
+  MissingRequiredSignatureClaimBonus,
+  WrongVaultBaseClaimBonus,
+  WrongRecipientClaimBonus,
+  WrongOwnerRecipientClaimBonus,
+  BonusStillLockedClaimBonus,
+  BonusLockEmptyClaimBonus,
+  BonusLockedClose,
+  NotEnoughAccountKeysRegisterReferrer,
+  MissingRequiredSignatureRegisterReferrer,
+  AccountAlreadyInitializedRegisterReferrer,
+  NotEnoughAccountKeysApproveReferrer,
+  MissingRequiredSignatureApproveReferrer,
+  NotOwnerApproveReferrer,
+  WrongSwapReferrer,
+  ReferrerNotApprovedSwap,
+  ReferrerIsUserSwap,
+  WrongOwnerBaseSwapBonus,
+  WrongOwnerQuoteSwapBonus,
+  InvalidParametersCreateBonusMax,
+  NotEnoughAccountKeysSetReferrerRates,
+  MissingRequiredSignatureSetReferrerRates,
+  NotOwnerSetReferrerRates,
+  InvalidParametersReferrerRatesMax,
+  NotEnoughAccountKeysInitConfig,
+  MissingRequiredSignatureInitConfig,
+  AccountAlreadyInitializedInitConfig,
+  NotEnoughAccountKeysSetFeeConfig,
+  MissingRequiredSignatureSetFeeConfig,
+  NotAdminSetFeeConfig,
+  InvalidParametersFeeBps,
+  InvalidParametersProtocolFeeCalculation,
+  WrongTreasurySwap,
+  NotEnoughAccountKeysUpdateConfig,
+  MissingRequiredSignatureUpdateConfig,
+  NotAdminUpdateConfig,
+  InvalidParametersQuoteMintsLen,
+  ProgramPausedCreate,
+  ProgramPausedSwap,
+  QuoteMintNotAllowedCreate,
+  InvalidAccountDataOwner,
+  InvalidAccountDataDiscriminator,
+  InvalidAccountDataVersion,
+  NotEnoughAccountKeysMigrate,
+  MissingRequiredSignatureMigrate,
+  NotOwnerMigrate,
+  AlreadyMigratedMigrate,
+  WrongProgramOwnerCreate,
+  WrongProgramOwnerSwap,
+  WrongProgramOwnerClose,
+  WrongProgramOwnerClaimBonus,
+  WrongProgramOwnerRegisterReferrer,
+  WrongProgramOwnerApproveReferrer,
+  WrongProgramOwnerSetReferrerRates,
+  WrongProgramOwnerSetFeeConfig,
+  WrongProgramOwnerUpdateConfig,
+  WrongProgramOwnerMigrate,
+  NotWritableCreate,
+  NotWritableSwap,
+  NotWritableClose,
+  NotWritableClaimBonus,
+  NotWritableRegisterReferrer,
+  NotWritableApproveReferrer,
+  NotWritableSetReferrerRates,
+  NotWritableInitConfig,
+  NotWritableSetFeeConfig,
+  NotWritableUpdateConfig,
+  NotWritableMigrate,
+  NonCanonicalBumpCreate,
+  NotEnoughAccountKeysCreateVault,
+  InvalidParametersStatsOverflow,
+  NotEnoughAccountKeysQuote,
+  WrongProgramOwnerQuote,
+  InvalidInstructionDataQuoteAmountZero,
+  ProgramPausedQuote,
+  WrongVaultBaseQuote,
+  WrongVaultQuoteQuote,
+  WrongMintBaseQuote,
+  WrongMintQuoteQuote,
+  ReferrerNotApprovedQuote,
+  InsufficientVaultBaseQuote,
+  InvalidParametersQuoteUnitsOverflow,
+  MissingRequiredSignatureSwapPayer,
+  NotEnoughAccountKeysBatchSwap,
+  MissingRequiredSignatureBatchSwap,
+  NotWritableBatchSwap,
+  WrongProgramOwnerBatchSwap,
+  InvalidInstructionDataBatchSwapQuoteInZero,
+  InvalidParametersBatchPoolCount,
+  ProgramPausedBatchSwap,
+  DuplicatePoolBatchSwap,
+  WrongVaultBaseBatchSwap,
+  WrongVaultQuoteBatchSwap,
+  WrongOwnerBaseBatchSwapVault,
+  WrongMintBaseBatchSwap,
+  WrongMintQuoteBatchSwap,
+  WrongTreasuryBatchSwap,
+  SlippageBatchSwap,
+  NotEnoughAccountKeysRoute,
+  MismatchedHopsRoute,
+  SlippageRoute,
+  NotEnoughAccountKeysRfqSwap,
+  WrongPoolRfqSwap,
+  WrongBuyerRfqSwap,
+  ExpiredRfqSwap,
+  InvalidSignatureRfqSwap,
+  WrongNoncesRfqSwap,
+  NonceUsedRfqSwap,
+  ExceedsMaxRfqSwap,
+  NotEnoughAccountKeysCreateMarket,
+  MissingRequiredSignatureCreateMarket,
+  NotWritableCreateMarket,
+  WrongProgramOwnerCreateMarket,
+  ProgramPausedCreateMarket,
+  SameMintCreateMarket,
+  QuoteMintNotAllowedCreateMarket,
+  AccountAlreadyInitializedCreateMarket,
+  NotEnoughAccountKeysPostOffer,
+  MissingRequiredSignaturePostOffer,
+  NotWritablePostOffer,
+  WrongProgramOwnerPostOffer,
+  ProgramPausedPostOffer,
+  InvalidParametersPostOffer,
+  WrongVaultBasePostOffer,
+  WrongMintBasePostOffer,
+  WrongMintQuotePostOffer,
+  MarketFullPostOffer,
+  NotEnoughAccountKeysCancelOffer,
+  MissingRequiredSignatureCancelOffer,
+  NotWritableCancelOffer,
+  WrongProgramOwnerCancelOffer,
+  WrongVaultBaseCancelOffer,
+  WrongMintBaseCancelOffer,
+  OfferNotOpenCancelOffer,
+  NotSellerCancelOffer,
+  NotEnoughAccountKeysMarketSwap,
+  MissingRequiredSignatureMarketSwap,
+  NotWritableMarketSwap,
+  WrongProgramOwnerMarketSwap,
+  InvalidInstructionDataMarketSwapQuoteInZero,
+  ProgramPausedMarketSwap,
+  WrongTreasuryMarketSwap,
+  WrongVaultBaseMarketSwap,
+  WrongMintBaseMarketSwap,
+  WrongMintQuoteMarketSwap,
+  SlippageMarketSwap,
+  NotEnoughAccountKeysCreateBuyOrder,
+  MissingRequiredSignatureCreateBuyOrder,
+  NotWritableCreateBuyOrder,
+  WrongProgramOwnerCreateBuyOrder,
+  ProgramPausedCreateBuyOrder,
+  SameMintCreateBuyOrder,
+  QuoteMintNotAllowedCreateBuyOrder,
+  InvalidParametersCreateBuyOrder,
+  WrongMintBaseCreateBuyOrder,
+  AccountAlreadyInitializedCreateBuyOrder,
+  NotEnoughAccountKeysFill,
+  MissingRequiredSignatureFill,
+  NotWritableFill,
+  WrongProgramOwnerFill,
+  InvalidInstructionDataFillQuoteOutZero,
+  ProgramPausedFill,
+  WrongTreasuryFill,
+  WrongVaultQuoteFill,
+  WrongBuyerBaseFill,
+  WrongMintBaseFill,
+  WrongMintQuoteFill,
+  InsufficientQuoteFill,
+  SlippageFill,
+  NotEnoughAccountKeysCancelBuyOrder,
+  MissingRequiredSignatureCancelBuyOrder,
+  NotWritableCancelBuyOrder,
+  WrongProgramOwnerCancelBuyOrder,
+  NotBuyerCancelBuyOrder,
+  WrongVaultQuoteCancelBuyOrder,
+  WrongMintQuoteCancelBuyOrder,
+  NotEnoughAccountKeysSetVolumeTiers,
+  MissingRequiredSignatureSetVolumeTiers,
+  NotWritableSetVolumeTiers,
+  WrongProgramOwnerSetVolumeTiers,
+  NotOwnerSetVolumeTiers,
+  InvalidParametersVolumeTiers,
+  InvalidParametersVolumeDiscount,
+  NotEnoughAccountKeysSetRoundingPolicy,
+  MissingRequiredSignatureSetRoundingPolicy,
+  NotWritableSetRoundingPolicy,
+  WrongProgramOwnerSetRoundingPolicy,
+  NotOwnerSetRoundingPolicy,
+  InvalidParametersRounding,
+  QuoteInBelowMinSwap,
+  QuoteInBelowMinQuote,
+  InvalidParametersCreatePriceExponent,
+  NotEnoughAccountKeysSetThrottle,
+  MissingRequiredSignatureSetThrottle,
+  NotWritableSetThrottle,
+  WrongProgramOwnerSetThrottle,
+  NotOwnerSetThrottle,
+  InvalidParametersThrottle,
+  RateLimitedSwap,
+  RateLimitedQuote,
+  WrongProgramDataInitConfig,
+  NotUpgradeAuthorityInitConfig,
+  OfferTooSmallPostOffer,
+  WrongEvictedSellerPostOffer,
 }
 
 export type SwapErrorArgs = SwapError;
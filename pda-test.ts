@@ -1,6 +1,6 @@
 #!/usr/bin/env tsx
 
-import { createSolanaRpc, getProgramDerivedAddress, address } from '@solana/kit';
+import { createSolanaRpc, getProgramDerivedAddress, getAddressEncoder, generateKeyPairSigner, address } from '@solana/kit';
 import { v4 as uuidv4, parse as uuidParse } from 'uuid';
 import { AQUA_SWAP_PROGRAM_ADDRESS } from './clients/js/src/generated/programs/aquaSwap';
 
@@ -16,10 +16,14 @@ async function main() {
   const uuidBytes = uuidParse(uuidStr); // Uint8Array(16)
   console.log('🆔 UUID:', uuidStr);
 
+  // Pools are namespaced by owner: ["swap", owner, uuid]
+  const owner = await generateKeyPairSigner();
+  console.log('👤 Owner:', owner.address);
+
   // Derive PDA using @solana/kit helper
   const result = await getProgramDerivedAddress({
     programAddress: AQUA_SWAP_PROGRAM_ADDRESS,
-    seeds: [uuidBytes],
+    seeds: ['swap', getAddressEncoder().encode(owner.address), uuidBytes],
   });
 
   // result shape differs by kit version; print it
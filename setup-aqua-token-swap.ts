@@ -44,8 +44,10 @@ import {
   getCreateInstruction,
   getSwapInstruction,
   getCloseInstruction,
+  getRegisterReferrerInstruction,
   AQUA_SWAP_PROGRAM_ADDRESS
 } from './clients/js/src/generated';
+import { findConfigPda, findReferrerPda, findSwapPda, withSwapAccounts, withoutLockAccounts } from './aqua-swap-accounts';
 
 const WSOL_MINT = 'So11111111111111111111111111111111111111112';
 const RPC_URL = 'https://api.mainnet-beta.solana.com';
@@ -100,7 +102,8 @@ async function main() {
   const uuidStr = uuidv4();
   const uuidBytes = uuidParse(uuidStr);
   const uuid = toBigIntLE(Buffer.from(uuidBytes));
-  const [swapPda, bumpSeed] = await getProgramDerivedAddress({ programAddress: AQUA_SWAP_PROGRAM_ADDRESS, seeds: [uuidBytes] });
+  const [swapPda, bumpSeed] = await findSwapPda(payer.address, uuidBytes);
+  const [configPda] = await findConfigPda();
   console.log('📍 Swap PDA:', swapPda, 'bump:', bumpSeed, 'uuid:', uuidStr, 'uuid int:', uuid.toString());
 
   // Create BASE mint
@@ -140,11 +143,19 @@ async function main() {
       swapAcc: address(swapPda),
       vaultBaseAcc: address(baseVaultAta),
       vaultQuoteAcc: address(quoteVaultWsolAta), // WSOL ATA; program stores its owner for SOL transfers
+      configAcc: configPda,
       createData: {
         uuid,
         price: 2500, // 400000 AQUA per 1 SOL
-        bonusBase: Number(BASE_BONUS_PERCENT),
-        bonusQuote: Number(QUOTE_BONUS_PERCENT),
+        bonusBase: BASE_BONUS_PERCENT,
+        bonusQuote: QUOTE_BONUS_PERCENT,
+        bonusBaseMax: BASE_BONUS_PERCENT,
+        bonusQuoteMax: QUOTE_BONUS_PERCENT,
+        bonusLockPeriod: 0, // base bonus paid instantly
+        referrerApproval: 0, // referrers are approved on registration
+        createVault: 0,
+        initialDeposit: 0,
+        priceExponent: 0,
         bumpSeed: Number(bumpSeed),
       },
     });
@@ -203,6 +214,25 @@ async function main() {
   }
   console.log('✅ Bonus base ATA prepared');
 
+  // Register the bonus recipient as a referrer of the pool (bonuses only go to referrers)
+  const [referrerPda] = await findReferrerPda(swapPda, bonusRecipient.address);
+  {
+    const { value: bh } = await rpc.getLatestBlockhash().send();
+    const registerIx = getRegisterReferrerInstruction({ walletAcc: bonusRecipient, swapAcc: address(swapPda), referrerAcc: referrerPda });
+    await pipe(
+      createTransactionMessage({ version: 0 }),
+      (tx) => setTransactionMessageFeePayerSigner(bonusRecipient, tx),
+      (tx) => setTransactionMessageLifetimeUsingBlockhash(bh, tx),
+      (tx) => appendTransactionMessageInstructions([registerIx], tx),
+      async (tx) => {
+        const signed = await signTransactionMessageWithSigners(tx);
+        await sendAndConfirmTransaction(signed as any, { commitment: 'confirmed' });
+        return null as unknown as string;
+      }
+    );
+  }
+  console.log('✅ Bonus recipient registered as referrer');
+
   // Compute WSOL temp ATA owned by swap PDA
   const [wsolTempAta] = await findAssociatedTokenPda({ mint: WSOL_MINT, owner: swapPda, tokenProgram: TOKEN_PROGRAM_ADDRESS });
 
@@ -228,8 +258,9 @@ async function main() {
   // Wrap SOL into user WSOL ATA
   instructions.push(getTransferSolInstruction({ source: swapUser, destination: userWsolAta, amount: swapAmountUnits }));
   instructions.push(getSyncNativeInstruction({ account: userWsolAta }));
-  // Swap instruction (program will create wsolTempAcc idempotently if needed)
-  const swapIx = getSwapInstruction({
+  // Swap instruction (program will create wsolTempAcc idempotently if needed);
+  // SOL-quoted pool with unlocked bonuses and no protocol fee
+  const swapIx = withSwapAccounts(getSwapInstruction({
     userAcc: swapUser,
     swapAcc: address(swapPda),
     vaultBaseAcc: address(baseVaultAta),
@@ -238,11 +269,13 @@ async function main() {
     userQuoteAcc: address(userWsolAta),
     baseMintAcc: address(baseMint.address),
     quoteMintAcc: address(WSOL_MINT),
+    configAcc: configPda,
+    wsolTempAcc: address(wsolTempAta), // WSOL ATA owned by swap PDA
     bonusBaseAcc: address(bonusBaseAta),
     bonusQuoteAcc: address(bonusRecipient.address), // system account receives SOL bonus
-    wsolTempAcc: address(wsolTempAta), // WSOL ATA owned by swap PDA
+    referrerAcc: referrerPda,
     swapData: { quoteIn: swapAmountUnits },
-  });
+  }), { wsol: true, bonus: true });
   instructions.push(swapIx);
 
   const sig = await pipe(
@@ -298,7 +331,7 @@ async function main() {
     const [payerBaseAta] = await findAssociatedTokenPda({ mint: baseMint.address, owner: payer.address, tokenProgram: TOKEN_PROGRAM_ADDRESS });
     const { value: bh2 } = await rpc.getLatestBlockhash().send();
     const ixCreatePayerBase = await getCreateAssociatedTokenIdempotentInstructionAsync({ mint: baseMint.address, payer, owner: payer.address });
-    const closeIx = getCloseInstruction({ ownerAcc: payer, swapAcc: address(swapPda), vaultBaseAcc: address(baseVaultAta), ownerBaseAcc: address(payerBaseAta) });
+    const closeIx = withoutLockAccounts(getCloseInstruction({ ownerAcc: payer, swapAcc: address(swapPda), vaultBaseAcc: address(baseVaultAta), ownerBaseAcc: address(payerBaseAta), baseMintAcc: baseMint }));
     await pipe(
       createTransactionMessage({ version: 0 }),
       (tx) => setTransactionMessageFeePayerSigner(payer, tx),
@@ -34,5 +34,71 @@ fn process_instruction(
         SwapProgramInstruction::Close => {
             instructions::close(accounts, instruction_data)
         },
+        SwapProgramInstruction::ClaimBonus => {
+            instructions::claim_bonus(accounts, instruction_data)
+        },
+        SwapProgramInstruction::RegisterReferrer => {
+            instructions::register_referrer(accounts, instruction_data)
+        },
+        SwapProgramInstruction::ApproveReferrer => {
+            instructions::approve_referrer(accounts, instruction_data)
+        },
+        SwapProgramInstruction::SetReferrerRates => {
+            instructions::set_referrer_rates(accounts, instruction_data)
+        },
+        SwapProgramInstruction::InitConfig => {
+            instructions::init_config(accounts, instruction_data)
+        },
+        SwapProgramInstruction::SetFeeConfig => {
+            instructions::set_fee_config(accounts, instruction_data)
+        },
+        SwapProgramInstruction::UpdateConfig => {
+            instructions::update_config(accounts, instruction_data)
+        },
+        SwapProgramInstruction::MigrateSwap => {
+            instructions::migrate_swap(accounts, instruction_data)
+        },
+        SwapProgramInstruction::Quote => {
+            instructions::quote(accounts, instruction_data)
+        },
+        SwapProgramInstruction::BatchSwap => {
+            instructions::batch_swap(accounts, instruction_data)
+        },
+        SwapProgramInstruction::Route => {
+            instructions::route(accounts, instruction_data)
+        },
+        SwapProgramInstruction::RfqSwap => {
+            instructions::rfq_swap(accounts, instruction_data)
+        },
+        SwapProgramInstruction::CreateMarket => {
+            instructions::create_market(accounts, instruction_data)
+        },
+        SwapProgramInstruction::PostOffer => {
+            instructions::post_offer(accounts, instruction_data)
+        },
+        SwapProgramInstruction::CancelOffer => {
+            instructions::cancel_offer(accounts, instruction_data)
+        },
+        SwapProgramInstruction::MarketSwap => {
+            instructions::market_swap(accounts, instruction_data)
+        },
+        SwapProgramInstruction::CreateBuyOrder => {
+            instructions::create_buy_order(accounts, instruction_data)
+        },
+        SwapProgramInstruction::Fill => {
+            instructions::fill(accounts, instruction_data)
+        },
+        SwapProgramInstruction::CancelBuyOrder => {
+            instructions::cancel_buy_order(accounts, instruction_data)
+        },
+        SwapProgramInstruction::SetVolumeTiers => {
+            instructions::set_volume_tiers(accounts, instruction_data)
+        },
+        SwapProgramInstruction::SetRoundingPolicy => {
+            instructions::set_rounding_policy(accounts, instruction_data)
+        },
+        SwapProgramInstruction::SetThrottle => {
+            instructions::set_throttle(accounts, instruction_data)
+        },
     }
 }
@@ -65,6 +65,258 @@ pub enum SwapError {
     // Other errors
     SameMintCreate,
     NotOwnerClose,
+
+    // Bonus lock errors
+    InvalidParametersCreateBonusLockPeriod,
+    InvalidParametersBonusLockedOverflow,
+    InsufficientVaultBaseSwap,
+    WrongBonusLockSwap,
+    NotEnoughAccountKeysClaimBonus,
+    MissingRequiredSignatureClaimBonus,
+    WrongVaultBaseClaimBonus,
+    WrongRecipientClaimBonus,
+    WrongOwnerRecipientClaimBonus,
+    BonusStillLockedClaimBonus,
+    BonusLockEmptyClaimBonus,
+    BonusLockedClose,
+
+    // Referrer errors
+    NotEnoughAccountKeysRegisterReferrer,
+    MissingRequiredSignatureRegisterReferrer,
+    AccountAlreadyInitializedRegisterReferrer,
+    NotEnoughAccountKeysApproveReferrer,
+    MissingRequiredSignatureApproveReferrer,
+    NotOwnerApproveReferrer,
+    WrongSwapReferrer,
+    ReferrerNotApprovedSwap,
+    ReferrerIsUserSwap,
+    WrongOwnerBaseSwapBonus,
+    WrongOwnerQuoteSwapBonus,
+
+    // Referrer rate errors
+    InvalidParametersCreateBonusMax,
+    NotEnoughAccountKeysSetReferrerRates,
+    MissingRequiredSignatureSetReferrerRates,
+    NotOwnerSetReferrerRates,
+    InvalidParametersReferrerRatesMax,
+
+    // Config errors
+    NotEnoughAccountKeysInitConfig,
+    MissingRequiredSignatureInitConfig,
+    AccountAlreadyInitializedInitConfig,
+    NotEnoughAccountKeysSetFeeConfig,
+    MissingRequiredSignatureSetFeeConfig,
+    NotAdminSetFeeConfig,
+    InvalidParametersFeeBps,
+    InvalidParametersProtocolFeeCalculation,
+    WrongTreasurySwap,
+    NotEnoughAccountKeysUpdateConfig,
+    MissingRequiredSignatureUpdateConfig,
+    NotAdminUpdateConfig,
+    InvalidParametersQuoteMintsLen,
+    ProgramPausedCreate,
+    ProgramPausedSwap,
+    QuoteMintNotAllowedCreate,
+
+    // Account layout errors
+    InvalidAccountDataOwner,
+    InvalidAccountDataDiscriminator,
+    InvalidAccountDataVersion,
+    NotEnoughAccountKeysMigrate,
+    MissingRequiredSignatureMigrate,
+    NotOwnerMigrate,
+    AlreadyMigratedMigrate,
+
+    // Account validation layer errors
+    WrongProgramOwnerCreate,
+    WrongProgramOwnerSwap,
+    WrongProgramOwnerClose,
+    WrongProgramOwnerClaimBonus,
+    WrongProgramOwnerRegisterReferrer,
+    WrongProgramOwnerApproveReferrer,
+    WrongProgramOwnerSetReferrerRates,
+    WrongProgramOwnerSetFeeConfig,
+    WrongProgramOwnerUpdateConfig,
+    WrongProgramOwnerMigrate,
+    NotWritableCreate,
+    NotWritableSwap,
+    NotWritableClose,
+    NotWritableClaimBonus,
+    NotWritableRegisterReferrer,
+    NotWritableApproveReferrer,
+    NotWritableSetReferrerRates,
+    NotWritableInitConfig,
+    NotWritableSetFeeConfig,
+    NotWritableUpdateConfig,
+    NotWritableMigrate,
+
+    // PDA bump errors
+    NonCanonicalBumpCreate,
+
+    // Vault creation errors
+    NotEnoughAccountKeysCreateVault,
+
+    // Pool statistics errors
+    InvalidParametersStatsOverflow,
+
+    // Quote errors
+    NotEnoughAccountKeysQuote,
+    WrongProgramOwnerQuote,
+    InvalidInstructionDataQuoteAmountZero,
+    ProgramPausedQuote,
+    WrongVaultBaseQuote,
+    WrongVaultQuoteQuote,
+    WrongMintBaseQuote,
+    WrongMintQuoteQuote,
+    ReferrerNotApprovedQuote,
+    InsufficientVaultBaseQuote,
+    InvalidParametersQuoteUnitsOverflow,
+
+    // Delegated swap errors
+    MissingRequiredSignatureSwapPayer,
+
+    // Batch swap errors
+    NotEnoughAccountKeysBatchSwap,
+    MissingRequiredSignatureBatchSwap,
+    NotWritableBatchSwap,
+    WrongProgramOwnerBatchSwap,
+    InvalidInstructionDataBatchSwapQuoteInZero,
+    InvalidParametersBatchPoolCount,
+    ProgramPausedBatchSwap,
+    DuplicatePoolBatchSwap,
+    WrongVaultBaseBatchSwap,
+    WrongVaultQuoteBatchSwap,
+    WrongOwnerBaseBatchSwapVault,
+    WrongMintBaseBatchSwap,
+    WrongMintQuoteBatchSwap,
+    WrongTreasuryBatchSwap,
+    SlippageBatchSwap,
+
+    // Route errors
+    NotEnoughAccountKeysRoute,
+    MismatchedHopsRoute,
+    SlippageRoute,
+
+    // RFQ errors
+    NotEnoughAccountKeysRfqSwap,
+    WrongPoolRfqSwap,
+    WrongBuyerRfqSwap,
+    ExpiredRfqSwap,
+    InvalidSignatureRfqSwap,
+    WrongNoncesRfqSwap,
+    NonceUsedRfqSwap,
+    ExceedsMaxRfqSwap,
+
+    // Market errors
+    NotEnoughAccountKeysCreateMarket,
+    MissingRequiredSignatureCreateMarket,
+    NotWritableCreateMarket,
+    WrongProgramOwnerCreateMarket,
+    ProgramPausedCreateMarket,
+    SameMintCreateMarket,
+    QuoteMintNotAllowedCreateMarket,
+    AccountAlreadyInitializedCreateMarket,
+    NotEnoughAccountKeysPostOffer,
+    MissingRequiredSignaturePostOffer,
+    NotWritablePostOffer,
+    WrongProgramOwnerPostOffer,
+    ProgramPausedPostOffer,
+    InvalidParametersPostOffer,
+    WrongVaultBasePostOffer,
+    WrongMintBasePostOffer,
+    WrongMintQuotePostOffer,
+    MarketFullPostOffer,
+    NotEnoughAccountKeysCancelOffer,
+    MissingRequiredSignatureCancelOffer,
+    NotWritableCancelOffer,
+    WrongProgramOwnerCancelOffer,
+    WrongVaultBaseCancelOffer,
+    WrongMintBaseCancelOffer,
+    OfferNotOpenCancelOffer,
+    NotSellerCancelOffer,
+    NotEnoughAccountKeysMarketSwap,
+    MissingRequiredSignatureMarketSwap,
+    NotWritableMarketSwap,
+    WrongProgramOwnerMarketSwap,
+    InvalidInstructionDataMarketSwapQuoteInZero,
+    ProgramPausedMarketSwap,
+    WrongTreasuryMarketSwap,
+    WrongVaultBaseMarketSwap,
+    WrongMintBaseMarketSwap,
+    WrongMintQuoteMarketSwap,
+    SlippageMarketSwap,
+
+    // Buy order errors
+    NotEnoughAccountKeysCreateBuyOrder,
+    MissingRequiredSignatureCreateBuyOrder,
+    NotWritableCreateBuyOrder,
+    WrongProgramOwnerCreateBuyOrder,
+    ProgramPausedCreateBuyOrder,
+    SameMintCreateBuyOrder,
+    QuoteMintNotAllowedCreateBuyOrder,
+    InvalidParametersCreateBuyOrder,
+    WrongMintBaseCreateBuyOrder,
+    AccountAlreadyInitializedCreateBuyOrder,
+    NotEnoughAccountKeysFill,
+    MissingRequiredSignatureFill,
+    NotWritableFill,
+    WrongProgramOwnerFill,
+    InvalidInstructionDataFillQuoteOutZero,
+    ProgramPausedFill,
+    WrongTreasuryFill,
+    WrongVaultQuoteFill,
+    WrongBuyerBaseFill,
+    WrongMintBaseFill,
+    WrongMintQuoteFill,
+    InsufficientQuoteFill,
+    SlippageFill,
+    NotEnoughAccountKeysCancelBuyOrder,
+    MissingRequiredSignatureCancelBuyOrder,
+    NotWritableCancelBuyOrder,
+    WrongProgramOwnerCancelBuyOrder,
+    NotBuyerCancelBuyOrder,
+    WrongVaultQuoteCancelBuyOrder,
+    WrongMintQuoteCancelBuyOrder,
+
+    // Volume discount errors
+    NotEnoughAccountKeysSetVolumeTiers,
+    MissingRequiredSignatureSetVolumeTiers,
+    NotWritableSetVolumeTiers,
+    WrongProgramOwnerSetVolumeTiers,
+    NotOwnerSetVolumeTiers,
+    InvalidParametersVolumeTiers,
+    InvalidParametersVolumeDiscount,
+
+    // Rounding policy errors
+    NotEnoughAccountKeysSetRoundingPolicy,
+    MissingRequiredSignatureSetRoundingPolicy,
+    NotWritableSetRoundingPolicy,
+    WrongProgramOwnerSetRoundingPolicy,
+    NotOwnerSetRoundingPolicy,
+    InvalidParametersRounding,
+    QuoteInBelowMinSwap,
+    QuoteInBelowMinQuote,
+
+    // Price exponent errors
+    InvalidParametersCreatePriceExponent,
+
+    // Rate limit errors
+    NotEnoughAccountKeysSetThrottle,
+    MissingRequiredSignatureSetThrottle,
+    NotWritableSetThrottle,
+    WrongProgramOwnerSetThrottle,
+    NotOwnerSetThrottle,
+    InvalidParametersThrottle,
+    RateLimitedSwap,
+    RateLimitedQuote,
+
+    // Config authority errors
+    WrongProgramDataInitConfig,
+    NotUpgradeAuthorityInitConfig,
+
+    // Market eviction errors
+    OfferTooSmallPostOffer,
+    WrongEvictedSellerPostOffer,
 }
 
 impl From<SwapError> for ProgramError {
@@ -0,0 +1,283 @@
+use pinocchio::{log::sol_log_data, pubkey::Pubkey};
+use shank::ShankType;
+
+use crate::states::{
+    utils::{to_bytes, DataLen},
+    Config,
+    Referrer,
+    VolumeTier,
+};
+
+/// Leading byte of every event emitted through `sol_log_data`. The event body follows
+/// immediately, laid out exactly as the matching `repr(C, packed)` struct below.
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, ShankType)]
+pub enum EventDiscriminator {
+    SwapCreated,
+    Swapped,
+    SwapClosed,
+    BonusClaimed,
+    ReferrerRegistered,
+    ReferrerUpdated,
+    ConfigUpdated,
+    SwapMigrated,
+    OfferPosted,
+    OfferCancelled,
+    OfferFilled,
+    BuyOrderCreated,
+    BuyOrderFilled,
+    BuyOrderCancelled,
+    VolumeTiersUpdated,
+    RoundingPolicyUpdated,
+    ThrottleUpdated,
+}
+
+pub trait Event: DataLen {
+    const DISCRIMINATOR: EventDiscriminator;
+}
+
+/// Largest encoded event (discriminator included).
+const MAX_EVENT_LEN: usize = 256;
+
+/// Emit `event` as a single `Program data:` log entry.
+pub fn emit<T: Event>(event: &T) {
+    let mut buf = [0u8; MAX_EVENT_LEN];
+    buf[0] = T::DISCRIMINATOR as u8;
+    buf[1..=T::LEN].copy_from_slice(unsafe { to_bytes(event) });
+    sol_log_data(&[&buf[..=T::LEN]]);
+}
+
+macro_rules! impl_event {
+    ($name:ident) => {
+        impl DataLen for $name {
+            const LEN: usize = core::mem::size_of::<$name>();
+        }
+
+        impl Event for $name {
+            const DISCRIMINATOR: EventDiscriminator = EventDiscriminator::$name;
+        }
+
+        // The discriminator and body must fit the `emit` buffer
+        const _: () = assert!($name::LEN < MAX_EVENT_LEN);
+    };
+}
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct SwapCreated {
+    pub swap: Pubkey,
+    pub owner: Pubkey,
+    pub vault_base: Pubkey,
+    pub vault_quote: Pubkey,
+    pub uuid: u128,
+    pub price: u64,
+    pub bonus_base: u64,
+    pub bonus_quote: u64,
+    pub initial_deposit: u64,
+    pub price_exponent: i8,
+}
+impl_event!(SwapCreated);
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct Swapped {
+    pub swap: Pubkey,
+    pub buyer: Pubkey,
+    pub quote_in: u64,
+    pub base_out: u64,
+    pub quote_to_vault: u64,
+    pub quote_bonus: u64,
+    pub base_bonus: u64,
+    pub protocol_fee: u64,
+    /// Price before the volume discount, which is reported in `discount_bps`.
+    pub price: u64,
+    /// Power of ten `price` is multiplied by.
+    pub price_exponent: i8,
+    /// 1 if `base_bonus` was accrued to a bonus lock rather than paid out.
+    pub base_bonus_locked: u8,
+    /// 1-based volume tier applied to the pool price (0 = none).
+    pub volume_tier: u8,
+    pub discount_bps: u16,
+}
+impl_event!(Swapped);
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct SwapClosed {
+    pub swap: Pubkey,
+    pub owner: Pubkey,
+    pub base_returned: u64,
+}
+impl_event!(SwapClosed);
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct BonusClaimed {
+    pub swap: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+impl_event!(BonusClaimed);
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct ReferrerRegistered {
+    pub swap: Pubkey,
+    pub wallet: Pubkey,
+    pub approved: u8,
+}
+impl_event!(ReferrerRegistered);
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct ReferrerUpdated {
+    pub swap: Pubkey,
+    pub wallet: Pubkey,
+    pub approved: u8,
+    pub custom_rates: u8,
+    pub bonus_base: u64,
+    pub bonus_quote: u64,
+}
+impl_event!(ReferrerUpdated);
+
+impl ReferrerUpdated {
+    pub fn new(referrer: &Referrer) -> Self {
+        Self {
+            swap: referrer.swap,
+            wallet: referrer.wallet,
+            approved: referrer.approved as u8,
+            custom_rates: referrer.custom_rates as u8,
+            bonus_base: referrer.bonus_base,
+            bonus_quote: referrer.bonus_quote,
+        }
+    }
+}
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct ConfigUpdated {
+    pub admin: Pubkey,
+    pub fee_treasury: Pubkey,
+    pub fee_bps: u16,
+    pub paused: u8,
+    pub allowed_quote_mints_len: u8,
+}
+impl_event!(ConfigUpdated);
+
+impl ConfigUpdated {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            admin: config.admin,
+            fee_treasury: config.fee_treasury,
+            fee_bps: config.fee_bps,
+            paused: config.paused as u8,
+            allowed_quote_mints_len: config.allowed_quote_mints_len,
+        }
+    }
+}
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct SwapMigrated {
+    pub swap: Pubkey,
+    pub version: u8,
+}
+impl_event!(SwapMigrated);
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct OfferPosted {
+    pub market: Pubkey,
+    pub offer_index: u8,
+    pub seller: Pubkey,
+    pub price: u64,
+    pub base_amount: u64,
+}
+impl_event!(OfferPosted);
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct OfferCancelled {
+    pub market: Pubkey,
+    pub offer_index: u8,
+    pub seller: Pubkey,
+    pub base_returned: u64,
+}
+impl_event!(OfferCancelled);
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct OfferFilled {
+    pub market: Pubkey,
+    pub offer_index: u8,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub quote_in: u64,
+    pub base_out: u64,
+    pub protocol_fee: u64,
+    /// Base left in the offer after this fill.
+    pub base_remaining: u64,
+}
+impl_event!(OfferFilled);
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct BuyOrderCreated {
+    pub order: Pubkey,
+    pub buyer: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub price: u64,
+    pub quote_amount: u64,
+}
+impl_event!(BuyOrderCreated);
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct BuyOrderFilled {
+    pub order: Pubkey,
+    pub seller: Pubkey,
+    pub price: u64,
+    pub base_in: u64,
+    pub quote_out: u64,
+    pub protocol_fee: u64,
+    /// Quote left in escrow after this fill.
+    pub quote_remaining: u64,
+}
+impl_event!(BuyOrderFilled);
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct BuyOrderCancelled {
+    pub order: Pubkey,
+    pub buyer: Pubkey,
+    pub quote_returned: u64,
+}
+impl_event!(BuyOrderCancelled);
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct VolumeTiersUpdated {
+    pub swap: Pubkey,
+    pub volume_tiers: [VolumeTier; 4],
+}
+impl_event!(VolumeTiersUpdated);
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct RoundingPolicyUpdated {
+    pub swap: Pubkey,
+    pub rounding: u8,
+    pub min_quote_in: u64,
+}
+impl_event!(RoundingPolicyUpdated);
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct ThrottleUpdated {
+    pub swap: Pubkey,
+    pub window_slots: u64,
+    pub max_base: u64,
+}
+impl_event!(ThrottleUpdated);
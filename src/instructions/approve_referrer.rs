@@ -0,0 +1,57 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    ProgramResult,
+};
+use shank::ShankAccount;
+use pinocchio_log::log;
+use crate::{
+    errors::SwapError,
+    events::{emit, ReferrerUpdated},
+    validation::{check_program_owner, check_signer, check_writable},
+    states::{
+        utils::{load_acc, load_acc_mut, load_ix_data, DataLen},
+        Referrer,
+        SwapState,
+    },
+};
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct ApproveReferrerData {
+    /// 1 to approve the referrer, 0 to revoke it.
+    pub approved: u8,
+}
+
+impl DataLen for ApproveReferrerData {
+    const LEN: usize = core::mem::size_of::<ApproveReferrerData>();
+}
+
+pub fn approve_referrer(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    log!("Approve Referrer");
+    let ix_data = unsafe { load_ix_data::<ApproveReferrerData>(data)? };
+    let [owner_acc, swap_acc, referrer_acc] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysApproveReferrer.into());
+    };
+
+    // Validate owner is signer
+    check_signer(owner_acc, SwapError::MissingRequiredSignatureApproveReferrer)?;
+    check_writable(&[referrer_acc], SwapError::NotWritableApproveReferrer)?;
+    check_program_owner(&[swap_acc, referrer_acc], SwapError::WrongProgramOwnerApproveReferrer)?;
+
+    let swap_state = unsafe { load_acc::<SwapState>(swap_acc) }?;
+    swap_state.check_pda(swap_acc.key())?;
+    if swap_state.owner != *owner_acc.key() {
+        return Err(SwapError::NotOwnerApproveReferrer.into());
+    }
+
+    let referrer_state = unsafe { load_acc_mut::<Referrer>(referrer_acc) }?;
+    Referrer::validate_pda(referrer_state.bump_seed, swap_acc.key(), &referrer_state.wallet, referrer_acc.key())?;
+    if referrer_state.swap != *swap_acc.key() {
+        return Err(SwapError::WrongSwapReferrer.into());
+    }
+    referrer_state.approved = ix_data.approved != 0;
+
+    emit(&ReferrerUpdated::new(referrer_state));
+    log!("Referrer approved: {}", referrer_state.approved);
+    Ok(())
+}
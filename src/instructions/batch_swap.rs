@@ -0,0 +1,309 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::set_return_data,
+    instruction::Signer,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use five8_const::decode_32_const;
+use shank::ShankAccount;
+use pinocchio_log::log;
+use pinocchio_token::{
+    instructions::TransferChecked,
+    state::{Mint, TokenAccount},
+};
+use crate::{
+    errors::SwapError,
+    events::{emit, Swapped},
+    validation::{check_program_owner, check_signer, check_writable},
+    states::{
+        utils::{load_acc, load_acc_mut, load_ix_data, to_bytes, DataLen},
+        Config,
+        Rounding,
+        SwapSeeds,
+        SwapState,
+    },
+};
+use super::swap::{compute_base_units, compute_quote_units, swap_amounts, Price, QuoteTransfer, SwapResult};
+
+/// Most pools a single BatchSwap can fill from.
+pub const MAX_BATCH_POOLS: usize = 8;
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct BatchSwapData {
+    /// Quote tokens the user is willing to spend across all pools.
+    pub quote_in: u64,
+    /// Least total base tokens the user accepts.
+    pub min_base_out: u64,
+    /// Number of pool entries following the fixed accounts (at most `MAX_BATCH_POOLS`).
+    pub pool_count: u8,
+}
+
+impl DataLen for BatchSwapData {
+    const LEN: usize = core::mem::size_of::<BatchSwapData>();
+}
+
+/// Buy one base mint from several pools, cheapest first, until the quote budget is spent
+/// or the pools run dry. Referral bonuses and volume discounts do not apply to batch fills,
+/// which are priced at each pool's list price. Pools whose share of the budget is below
+/// their `min_quote_in` are skipped, and throttled pools only fill up to what their throttle
+/// currently allows.
+///
+/// Each pool entry is `swap_acc, vault_base_acc, vault_quote_acc`, plus the pool's
+/// `wsol_temp_acc` when the quote mint is WSOL. After the entries come the system and
+/// associated token programs for SOL quotes, `fee_treasury_acc` when the protocol fee
+/// is set, and an optional `payer_acc`.
+pub fn batch_swap(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    log!("Batch Swap");
+    let ix_data = unsafe { load_ix_data::<BatchSwapData>(data)? };
+    let [
+        user_acc,
+        user_base_acc,
+        user_quote_acc,
+        base_mint_acc,
+        quote_mint_acc,
+        token_program_acc,
+        config_acc,
+        rest @ ..
+    ] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysBatchSwap.into());
+    };
+
+    check_signer(user_acc, SwapError::MissingRequiredSignatureBatchSwap)?;
+    check_writable(&[user_base_acc, user_quote_acc], SwapError::NotWritableBatchSwap)?;
+    check_program_owner(&[config_acc], SwapError::WrongProgramOwnerBatchSwap)?;
+    if ix_data.quote_in == 0 {
+        return Err(SwapError::InvalidInstructionDataBatchSwapQuoteInZero.into());
+    }
+    let pool_count = ix_data.pool_count as usize;
+    if pool_count == 0 || pool_count > MAX_BATCH_POOLS {
+        return Err(SwapError::InvalidParametersBatchPoolCount.into());
+    }
+
+    let config_state = unsafe { load_acc::<Config>(config_acc) }?;
+    Config::validate_pda(config_state.bump_seed, config_acc.key())?;
+    if config_state.paused {
+        return Err(SwapError::ProgramPausedBatchSwap.into());
+    }
+
+    // Split off the pool entries, then the shared trailing accounts
+    let quote_sol = *quote_mint_acc.key() == decode_32_const("So11111111111111111111111111111111111111112");
+    let entry_len = if quote_sol { 4 } else { 3 };
+    if rest.len() < pool_count * entry_len {
+        return Err(SwapError::NotEnoughAccountKeysBatchSwap.into());
+    }
+    let (pool_accs, rest) = rest.split_at(pool_count * entry_len);
+    let mut rest = rest.iter();
+    let mut next = || rest.next().ok_or(ProgramError::from(SwapError::NotEnoughAccountKeysBatchSwap));
+    let mut system_program_acc = None;
+    if quote_sol {
+        system_program_acc = Some(next()?);
+        let _ata_program_acc = next()?;
+    }
+    let mut fee_treasury_acc = None;
+    if config_state.fee_bps != 0 {
+        fee_treasury_acc = Some(next()?);
+    }
+    let payer_acc = next().unwrap_or(user_acc);
+    check_signer(payer_acc, SwapError::MissingRequiredSignatureSwapPayer)?;
+    check_writable(&[payer_acc], SwapError::NotWritableBatchSwap)?;
+
+    let base_decimals = Mint::from_account_info(base_mint_acc)?.decimals();
+    let mut quote_decimals = 9;
+    if !quote_sol {
+        quote_decimals = Mint::from_account_info(quote_mint_acc)?.decimals();
+    }
+    let user_base = TokenAccount::from_account_info(user_base_acc)?;
+    if user_base.mint() != base_mint_acc.key() {
+        return Err(SwapError::WrongMintBaseBatchSwap.into());
+    }
+    drop(user_base);
+    let user_quote = TokenAccount::from_account_info(user_quote_acc)?;
+    if user_quote.mint() != quote_mint_acc.key() {
+        return Err(SwapError::WrongMintQuoteBatchSwap.into());
+    }
+    drop(user_quote);
+
+    if let Some(fee_treasury_acc) = fee_treasury_acc {
+        if quote_sol {
+            if *fee_treasury_acc.key() != config_state.fee_treasury {
+                return Err(SwapError::WrongTreasuryBatchSwap.into());
+            }
+        } else {
+            let treasury_ata = TokenAccount::from_account_info(fee_treasury_acc)?;
+            if *treasury_ata.owner() != config_state.fee_treasury {
+                return Err(SwapError::WrongTreasuryBatchSwap.into());
+            }
+        }
+    }
+
+    // Validate every pool, then fill them cheapest first
+    let mut prices = [Price::scaled(0); MAX_BATCH_POOLS];
+    let mut order = [0usize; MAX_BATCH_POOLS];
+    for i in 0..pool_count {
+        let entry = &pool_accs[i * entry_len..(i + 1) * entry_len];
+        if pool_accs[..i * entry_len].chunks(entry_len).any(|other| other[0].key() == entry[0].key()) {
+            return Err(SwapError::DuplicatePoolBatchSwap.into());
+        }
+        prices[i] = validate_pool(entry, base_mint_acc, quote_mint_acc, quote_sol)?;
+        order[i] = i;
+    }
+    order[..pool_count].sort_unstable_by(|&a, &b| prices[a].compare(&prices[b]));
+
+    let slot = Clock::get()?.slot;
+    let mut quote_remaining = ix_data.quote_in;
+    let mut total_base_out: u64 = 0;
+    let mut total_quote_to_vault: u64 = 0;
+    for &i in &order[..pool_count] {
+        if quote_remaining == 0 {
+            break;
+        }
+        let entry = &pool_accs[i * entry_len..(i + 1) * entry_len];
+        let (swap_acc, vault_base_acc, vault_quote_acc) = (&entry[0], &entry[1], &entry[2]);
+        let swap_state = unsafe { load_acc_mut::<SwapState>(swap_acc) }?;
+
+        let vault_base_amount = TokenAccount::from_account_info(vault_base_acc)?.amount();
+        let base_available = swap_state
+            .available_base(vault_base_amount)
+            .min(swap_state.throttle_allowance(slot));
+        if base_available == 0 {
+            continue;
+        }
+        let rounding = Rounding::try_from(swap_state.rounding)?;
+        let price = prices[i];
+        let quote_in = quote_remaining.min(max_quote_in(base_available, price, base_decimals, quote_decimals, rounding));
+        if quote_in < swap_state.min_quote_in {
+            continue;
+        }
+        // Too little quote left to buy a single base unit here
+        let Ok(amounts) = swap_amounts(price, config_state.fee_bps, 0, 0, quote_in, base_decimals, quote_decimals, rounding) else {
+            continue;
+        };
+
+        let swap_seeds = SwapSeeds::new(swap_state);
+        let (signer_seeds, seeds_len) = swap_seeds.seeds();
+        let signers = [Signer::from(&signer_seeds[..seeds_len])];
+        let quote_transfer = QuoteTransfer {
+            user_acc,
+            payer_acc,
+            user_quote_acc,
+            quote_mint_acc,
+            swap_acc,
+            token_program_acc,
+            wsol: system_program_acc.map(|system_program_acc| [&entry[3], system_program_acc]),
+            decimals: quote_decimals,
+        };
+
+        log!("Transfer quote from user to vault: {}", amounts.quote_to_vault);
+        quote_transfer.invoke(vault_quote_acc, amounts.quote_to_vault, &signers)?;
+        if amounts.protocol_fee > 0 {
+            let fee_treasury_acc = fee_treasury_acc.ok_or(SwapError::NotEnoughAccountKeysBatchSwap)?;
+            log!("Transfer quote from user to treasury: {}", amounts.protocol_fee);
+            quote_transfer.invoke(fee_treasury_acc, amounts.protocol_fee, &signers)?;
+        }
+        log!("Transfer base token from vault to user: {}", amounts.base_out);
+        TransferChecked {
+            from: vault_base_acc,
+            mint: base_mint_acc,
+            to: user_base_acc,
+            authority: swap_acc,
+            amount: amounts.base_out,
+            decimals: base_decimals,
+        }
+        .invoke_signed(&signers)?;
+
+        swap_state.record_throttle(amounts.base_out, slot)?;
+        swap_state.record_swap(amounts.quote_to_vault, amounts.base_out, 0, 0, slot)?;
+        emit(&Swapped {
+            swap: *swap_acc.key(),
+            buyer: *user_acc.key(),
+            quote_in: amounts.quote_in,
+            base_out: amounts.base_out,
+            quote_to_vault: amounts.quote_to_vault,
+            quote_bonus: 0,
+            base_bonus: 0,
+            protocol_fee: amounts.protocol_fee,
+            price: swap_state.price,
+            price_exponent: swap_state.price_exponent,
+            base_bonus_locked: 0,
+            volume_tier: 0,
+            discount_bps: 0,
+        });
+
+        quote_remaining -= quote_in;
+        total_base_out = total_base_out
+            .checked_add(amounts.base_out)
+            .ok_or(SwapError::InvalidParametersStatsOverflow)?;
+        total_quote_to_vault = total_quote_to_vault
+            .checked_add(amounts.quote_to_vault)
+            .ok_or(SwapError::InvalidParametersStatsOverflow)?;
+    }
+
+    if total_base_out < ix_data.min_base_out {
+        return Err(SwapError::SlippageBatchSwap.into());
+    }
+
+    let result = SwapResult {
+        base_out: total_base_out,
+        quote_to_vault: total_quote_to_vault,
+        quote_bonus: 0,
+        base_bonus: 0,
+        volume_tier: 0,
+        discount_bps: 0,
+    };
+    set_return_data(unsafe { to_bytes(&result) });
+    log!("Batch Swap Completed: {}", total_base_out);
+    Ok(())
+}
+
+/// Check one pool entry against the batch's mints and return the pool's price.
+fn validate_pool(
+    entry: &[AccountInfo],
+    base_mint_acc: &AccountInfo,
+    quote_mint_acc: &AccountInfo,
+    quote_sol: bool,
+) -> Result<Price, ProgramError> {
+    let (swap_acc, vault_base_acc, vault_quote_acc) = (&entry[0], &entry[1], &entry[2]);
+    check_program_owner(&[swap_acc], SwapError::WrongProgramOwnerBatchSwap)?;
+    check_writable(&[swap_acc, vault_base_acc, vault_quote_acc], SwapError::NotWritableBatchSwap)?;
+    if let Some(wsol_temp_acc) = entry.get(3) {
+        check_writable(&[wsol_temp_acc], SwapError::NotWritableBatchSwap)?;
+    }
+
+    let swap_state = unsafe { load_acc::<SwapState>(swap_acc) }?;
+    swap_state.check_pda(swap_acc.key())?;
+    if swap_state.base != *vault_base_acc.key() {
+        return Err(SwapError::WrongVaultBaseBatchSwap.into());
+    }
+    if swap_state.quote != *vault_quote_acc.key() || swap_state.quote_sol != quote_sol {
+        return Err(SwapError::WrongVaultQuoteBatchSwap.into());
+    }
+
+    let vault_base = TokenAccount::from_account_info(vault_base_acc)?;
+    if vault_base.owner() != swap_acc.key() {
+        return Err(SwapError::WrongOwnerBaseBatchSwapVault.into());
+    }
+    if vault_base.mint() != base_mint_acc.key() {
+        return Err(SwapError::WrongMintBaseBatchSwap.into());
+    }
+    if !quote_sol {
+        let vault_quote = TokenAccount::from_account_info(vault_quote_acc)?;
+        if vault_quote.mint() != quote_mint_acc.key() {
+            return Err(SwapError::WrongMintQuoteBatchSwap.into());
+        }
+    }
+    Ok(Price::new(swap_state.price, swap_state.price_exponent))
+}
+
+/// Largest `quote_in` whose base out fits in `base_available`.
+pub(crate) fn max_quote_in(base_available: u64, price: Price, base_decimals: u8, quote_decimals: u8, rounding: Rounding) -> u64 {
+    let Ok(quote_in) = compute_quote_units(base_available, price, base_decimals, quote_decimals) else {
+        return u64::MAX;
+    };
+    match compute_base_units(quote_in as u128, price, base_decimals, quote_decimals, rounding) {
+        Ok(base_out) if base_out <= base_available => quote_in,
+        _ => quote_in - 1,
+    }
+}
@@ -0,0 +1,91 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Signer,
+    ProgramResult,
+};
+use pinocchio_log::log;
+use pinocchio_token::{
+    instructions::{CloseAccount, TransferChecked},
+    state::{Mint, TokenAccount},
+};
+use crate::{
+    errors::SwapError,
+    events::{emit, BuyOrderCancelled},
+    validation::{check_program_owner, check_signer, check_writable},
+    states::{
+        utils::load_acc,
+        BuyOrder,
+        BuyOrderSeeds,
+    },
+};
+
+/// Return the unfilled quote to the buyer and close the order and its vault.
+pub fn cancel_buy_order(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    log!("Cancel Buy Order");
+    let [buyer_acc, order_acc, vault_quote_acc, buyer_quote_acc, quote_mint_acc, _token_program] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysCancelBuyOrder.into());
+    };
+
+    check_signer(buyer_acc, SwapError::MissingRequiredSignatureCancelBuyOrder)?;
+    check_writable(&[buyer_acc, order_acc, vault_quote_acc, buyer_quote_acc], SwapError::NotWritableCancelBuyOrder)?;
+    check_program_owner(&[order_acc], SwapError::WrongProgramOwnerCancelBuyOrder)?;
+
+    let order_state = unsafe { load_acc::<BuyOrder>(order_acc) }?;
+    BuyOrder::validate_pda(order_state.bump_seed, &order_state.buyer, order_state.uuid, order_acc.key())?;
+    if order_state.buyer != *buyer_acc.key() {
+        return Err(SwapError::NotBuyerCancelBuyOrder.into());
+    }
+    if order_state.vault_quote != *vault_quote_acc.key() {
+        return Err(SwapError::WrongVaultQuoteCancelBuyOrder.into());
+    }
+    if order_state.quote_mint != *quote_mint_acc.key() {
+        return Err(SwapError::WrongMintQuoteCancelBuyOrder.into());
+    }
+
+    // Anything sent to the vault outside of fills goes back to the buyer too
+    let quote_returned = TokenAccount::from_account_info(vault_quote_acc)?.amount();
+    let quote_decimals = Mint::from_account_info(quote_mint_acc)?.decimals();
+    let order_seeds = BuyOrderSeeds::new(order_state);
+    let signer_seeds = order_seeds.seeds();
+    let signers = [Signer::from(&signer_seeds[..])];
+
+    if quote_returned > 0 {
+        log!("Transfer quote from escrow to buyer: {}", quote_returned);
+        TransferChecked {
+            from: vault_quote_acc,
+            mint: quote_mint_acc,
+            to: buyer_quote_acc,
+            authority: order_acc,
+            amount: quote_returned,
+            decimals: quote_decimals,
+        }
+        .invoke_signed(&signers)?;
+    }
+
+    CloseAccount {
+        account: vault_quote_acc,
+        destination: buyer_acc,
+        authority: order_acc,
+    }
+    .invoke_signed(&signers)?;
+
+    let order_lamports = unsafe { *order_acc.borrow_lamports_unchecked() };
+    if order_lamports > 0 {
+        unsafe {
+            *order_acc.borrow_mut_lamports_unchecked() -= order_lamports;
+            *buyer_acc.borrow_mut_lamports_unchecked() += order_lamports;
+        }
+    }
+    unsafe {
+        let data = order_acc.borrow_mut_data_unchecked();
+        data.fill(0);
+    }
+
+    emit(&BuyOrderCancelled {
+        order: *order_acc.key(),
+        buyer: *buyer_acc.key(),
+        quote_returned,
+    });
+    log!("Buy Order Cancelled");
+    Ok(())
+}
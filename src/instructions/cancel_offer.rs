@@ -0,0 +1,101 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    ProgramResult,
+};
+use shank::ShankAccount;
+use pinocchio_log::log;
+use pinocchio_token::{
+    instructions::TransferChecked,
+    state::Mint,
+};
+use crate::{
+    errors::SwapError,
+    events::{emit, OfferCancelled},
+    validation::{check_program_owner, check_signer, check_writable},
+    states::{
+        utils::{load_acc_mut, load_ix_data, DataLen},
+        Market,
+        Offer,
+        MAX_MARKET_OFFERS,
+    },
+};
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct CancelOfferData {
+    pub offer_index: u8,
+}
+
+impl DataLen for CancelOfferData {
+    const LEN: usize = core::mem::size_of::<CancelOfferData>();
+}
+
+/// Withdraw the unfilled part of an offer back to the seller and free its slot.
+pub fn cancel_offer(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    log!("Cancel Offer");
+    let ix_data = unsafe { load_ix_data::<CancelOfferData>(data)? };
+    let [seller_acc, market_acc, vault_base_acc, seller_base_acc, base_mint_acc, _token_program] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysCancelOffer.into());
+    };
+
+    check_signer(seller_acc, SwapError::MissingRequiredSignatureCancelOffer)?;
+    check_writable(&[market_acc, vault_base_acc, seller_base_acc], SwapError::NotWritableCancelOffer)?;
+    check_program_owner(&[market_acc], SwapError::WrongProgramOwnerCancelOffer)?;
+
+    let market_state = unsafe { load_acc_mut::<Market>(market_acc) }?;
+    Market::validate_pda(market_state.bump_seed, &market_state.base_mint, &market_state.quote_mint, market_acc.key())?;
+    if market_state.vault_base != *vault_base_acc.key() {
+        return Err(SwapError::WrongVaultBaseCancelOffer.into());
+    }
+    if market_state.base_mint != *base_mint_acc.key() {
+        return Err(SwapError::WrongMintBaseCancelOffer.into());
+    }
+    let offer_index = ix_data.offer_index as usize;
+    if offer_index >= MAX_MARKET_OFFERS || !market_state.offers[offer_index].is_open() {
+        return Err(SwapError::OfferNotOpenCancelOffer.into());
+    }
+    let offer = market_state.offers[offer_index];
+    if offer.seller != *seller_acc.key() {
+        return Err(SwapError::NotSellerCancelOffer.into());
+    }
+
+    let base_decimals = Mint::from_account_info(base_mint_acc)?.decimals();
+    let base_mint = market_state.base_mint;
+    let quote_mint = market_state.quote_mint;
+    let pda_bump_bytes = [market_state.bump_seed];
+    let signer_seeds = [
+        Seed::from(Market::SEED),
+        Seed::from(&base_mint),
+        Seed::from(&quote_mint),
+        Seed::from(&pda_bump_bytes[..]),
+    ];
+    let signers = [Signer::from(&signer_seeds[..])];
+
+    log!("Transfer base token from market vault to seller: {}", offer.base_remaining);
+    TransferChecked {
+        from: vault_base_acc,
+        mint: base_mint_acc,
+        to: seller_base_acc,
+        authority: market_acc,
+        amount: offer.base_remaining,
+        decimals: base_decimals,
+    }
+    .invoke_signed(&signers)?;
+
+    market_state.offers[offer_index] = Offer {
+        seller: [0u8; 32],
+        seller_quote: [0u8; 32],
+        price: 0,
+        base_remaining: 0,
+    };
+
+    emit(&OfferCancelled {
+        market: *market_acc.key(),
+        offer_index: ix_data.offer_index,
+        seller: *seller_acc.key(),
+        base_returned: offer.base_remaining,
+    });
+    log!("Offer Cancelled");
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Signer,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use pinocchio_log::log;
+use pinocchio_token::{
+    instructions::TransferChecked,
+    state::{Mint, TokenAccount},
+};
+use crate::{
+    errors::SwapError,
+    events::{emit, BonusClaimed},
+    validation::{check_program_owner, check_signer, check_writable},
+    states::{
+        utils::load_acc_mut,
+        BonusLock,
+        SwapState,
+        SwapSeeds,
+    },
+};
+
+pub fn claim_bonus(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    log!("Claim Bonus");
+    let [
+        recipient_owner_acc,
+        swap_acc,
+        bonus_lock_acc,
+        vault_base_acc,
+        recipient_base_acc,
+        base_mint_acc,
+        _token_program,
+    ] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysClaimBonus.into());
+    };
+
+    // Validate recipient owner is signer
+    check_signer(recipient_owner_acc, SwapError::MissingRequiredSignatureClaimBonus)?;
+    check_writable(
+        &[recipient_owner_acc, swap_acc, bonus_lock_acc, vault_base_acc, recipient_base_acc],
+        SwapError::NotWritableClaimBonus,
+    )?;
+    check_program_owner(&[swap_acc, bonus_lock_acc], SwapError::WrongProgramOwnerClaimBonus)?;
+
+    // Load swap state
+    let swap_state = unsafe { load_acc_mut::<SwapState>(swap_acc) }?;
+    swap_state.check_pda(swap_acc.key())?;
+    if swap_state.base != *vault_base_acc.key() {
+        return Err(SwapError::WrongVaultBaseClaimBonus.into());
+    }
+
+    // Only the owner of the recipient token account may claim
+    let recipient_base = TokenAccount::from_account_info(recipient_base_acc)?;
+    if recipient_base.owner() != recipient_owner_acc.key() {
+        return Err(SwapError::WrongOwnerRecipientClaimBonus.into());
+    }
+    drop(recipient_base);
+
+    // Release every tranche whose unlock time has passed
+    let now = Clock::get()?.unix_timestamp;
+    let (amount, emptied) = BonusLock::release(bonus_lock_acc, swap_acc.key(), recipient_base_acc.key(), Some(now))?;
+    if amount == 0 {
+        return Err(if emptied {
+            SwapError::BonusLockEmptyClaimBonus
+        } else {
+            SwapError::BonusStillLockedClaimBonus
+        }
+        .into());
+    }
+
+    let base_decimals = Mint::from_account_info(base_mint_acc)?.decimals();
+
+    let swap_seeds = SwapSeeds::new(swap_state);
+    let (signer_seeds, seeds_len) = swap_seeds.seeds();
+    let signers = [Signer::from(&signer_seeds[..seeds_len])];
+
+    log!("Transfer unlocked base token bonus: {}", amount);
+    TransferChecked {
+        from: vault_base_acc,
+        mint: base_mint_acc,
+        to: recipient_base_acc,
+        authority: swap_acc,
+        amount,
+        decimals: base_decimals,
+    }
+    .invoke_signed(&signers)?;
+
+    swap_state.bonus_locked = swap_state.bonus_locked.saturating_sub(amount);
+
+    // Close the bonus lock once fully claimed and return its rent to the recipient owner
+    if emptied {
+        let lock_lamports = unsafe { *bonus_lock_acc.borrow_lamports_unchecked() };
+        unsafe {
+            *bonus_lock_acc.borrow_mut_lamports_unchecked() -= lock_lamports;
+            *recipient_owner_acc.borrow_mut_lamports_unchecked() += lock_lamports;
+            bonus_lock_acc.borrow_mut_data_unchecked().fill(0);
+        }
+    }
+
+    emit(&BonusClaimed {
+        swap: *swap_acc.key(),
+        recipient: *recipient_base_acc.key(),
+        amount,
+    });
+    log!("Bonus Claimed");
+    Ok(())
+}
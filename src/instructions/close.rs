@@ -1,34 +1,54 @@
 use pinocchio::{
     account_info::AccountInfo,
-    instruction::{Seed, Signer},
+    instruction::Signer,
     ProgramResult,
 };
 use pinocchio_log::log;
 use pinocchio_token::{
-    instructions::CloseAccount,
-    state::TokenAccount,
+    instructions::{CloseAccount, TransferChecked},
+    state::{Mint, TokenAccount},
 };
 use crate::{
     errors::SwapError,
+    events::{emit, BonusClaimed, SwapClosed},
+    validation::{check_program_owner, check_signer, check_writable},
     states::{
-        utils::load_acc_unchecked,
+        utils::load_acc_mut,
+        BonusLock,
         SwapState,
+        SwapSeeds,
     },
 };
 
+/// Close the pool and return the vault to the owner. Outstanding bonus locks are passed
+/// as trailing `[bonus_lock_acc, recipient_base_acc]` pairs and paid out in full first,
+/// locked or not, so closing never waits on referrers to claim. Their rent goes to the
+/// owner, as the closer of each lock.
 pub fn close(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
     log!("Close Swap");
-    let [owner_acc, swap_acc, vault_base_acc, owner_base_acc, _token_program] = accounts else {
+    let [
+        owner_acc,
+        swap_acc,
+        vault_base_acc,
+        owner_base_acc,
+        base_mint_acc,
+        _token_program,
+        lock_accs @ ..,
+    ] = accounts else {
         return Err(SwapError::NotEnoughAccountKeysClose.into());
     };
+    if lock_accs.len() % 2 != 0 {
+        return Err(SwapError::NotEnoughAccountKeysClose.into());
+    }
 
     // Validate owner is signer
-    if !owner_acc.is_signer() {
-        return Err(SwapError::MissingRequiredSignatureClose.into());
-    }
+    check_signer(owner_acc, SwapError::MissingRequiredSignatureClose)?;
+    check_writable(&[owner_acc, swap_acc, vault_base_acc, owner_base_acc], SwapError::NotWritableClose)?;
+    check_program_owner(&[swap_acc], SwapError::WrongProgramOwnerClose)?;
 
     // Load and validate swap state
-    let swap_state = unsafe { load_acc_unchecked::<SwapState>(swap_acc.borrow_data_unchecked()) }?;
+    let swap_state = unsafe { load_acc_mut::<SwapState>(swap_acc) }?;
+    swap_state.check_pda(swap_acc.key())?;
     
     // Validate owner matches
     if swap_state.owner != *owner_acc.key() {
@@ -40,6 +60,49 @@ pub fn close(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
         return Err(SwapError::WrongVaultBaseClose.into());
     }
 
+    let base_decimals = Mint::from_account_info(base_mint_acc)?.decimals();
+
+    // Locked bonuses are still owed to referrers out of the vault
+    for pair in lock_accs.chunks_exact(2) {
+        let (bonus_lock_acc, recipient_base_acc) = (&pair[0], &pair[1]);
+        check_writable(&[bonus_lock_acc, recipient_base_acc], SwapError::NotWritableClose)?;
+        let (amount, _) = BonusLock::release(bonus_lock_acc, swap_acc.key(), recipient_base_acc.key(), None)?;
+        if amount > 0 {
+            let swap_seeds = SwapSeeds::new(swap_state);
+            let (signer_seeds, seeds_len) = swap_seeds.seeds();
+            let signers = [Signer::from(&signer_seeds[..seeds_len])];
+
+            log!("Settle base token bonus: {}", amount);
+            TransferChecked {
+                from: vault_base_acc,
+                mint: base_mint_acc,
+                to: recipient_base_acc,
+                authority: swap_acc,
+                amount,
+                decimals: base_decimals,
+            }
+            .invoke_signed(&signers)?;
+            swap_state.bonus_locked = swap_state.bonus_locked.saturating_sub(amount);
+        }
+
+        // Rent of the settled lock goes to the owner closing it
+        let lock_lamports = unsafe { *bonus_lock_acc.borrow_lamports_unchecked() };
+        unsafe {
+            *bonus_lock_acc.borrow_mut_lamports_unchecked() -= lock_lamports;
+            *owner_acc.borrow_mut_lamports_unchecked() += lock_lamports;
+            bonus_lock_acc.borrow_mut_data_unchecked().fill(0);
+        }
+
+        emit(&BonusClaimed {
+            swap: *swap_acc.key(),
+            recipient: *recipient_base_acc.key(),
+            amount,
+        });
+    }
+    if swap_state.bonus_locked > 0 {
+        return Err(SwapError::BonusLockedClose.into());
+    }
+
     // Load vault base token account and extract needed values
     let vault_base_token = TokenAccount::from_account_info(vault_base_acc)?;
     let vault_mint = *vault_base_token.mint();
@@ -68,32 +131,26 @@ pub fn close(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
         // log!("Transferring {} base tokens back to owner", transfer_amount);
         
         // Create PDA seeds for signing
-        let uuid_binding = swap_state.uuid.to_le_bytes();
-        let pda_bump_bytes = [swap_state.bump_seed];
-        let signer_seeds = [
-            Seed::from(&uuid_binding),
-            Seed::from(&pda_bump_bytes[..]),
-        ];
-        let signers = [Signer::from(&signer_seeds[..])];
+        let swap_seeds = SwapSeeds::new(swap_state);
+        let (signer_seeds, seeds_len) = swap_seeds.seeds();
+        let signers = [Signer::from(&signer_seeds[..seeds_len])];
 
         // Transfer all tokens from vault to owner
-        pinocchio_token::instructions::Transfer {
+        TransferChecked {
             from: vault_base_acc,
+            mint: base_mint_acc,
             to: owner_base_acc,
             authority: swap_acc,
             amount: transfer_amount,
+            decimals: base_decimals,
         }
         .invoke_signed(&signers)?;
     }
     
     // Create PDA seeds for signing the close operation
-    let uuid_binding = swap_state.uuid.to_le_bytes();
-    let pda_bump_bytes = [swap_state.bump_seed];
-    let signer_seeds = [
-        Seed::from(&uuid_binding),
-        Seed::from(&pda_bump_bytes[..]),
-    ];
-    let signers = [Signer::from(&signer_seeds[..])];
+    let swap_seeds = SwapSeeds::new(swap_state);
+    let (signer_seeds, seeds_len) = swap_seeds.seeds();
+    let signers = [Signer::from(&signer_seeds[..seeds_len])];
 
     // Close the vault token account
     CloseAccount {
@@ -118,6 +175,11 @@ pub fn close(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
         data.fill(0);
     }
 
+    emit(&SwapClosed {
+        swap: *swap_acc.key(),
+        owner: *owner_acc.key(),
+        base_returned: transfer_amount,
+    });
     log!("Swap Closed");
     Ok(())
 }
@@ -8,14 +8,28 @@ use five8_const::decode_32_const;
 use shank::ShankAccount;
 use pinocchio_log::log;
 use pinocchio_system::instructions::CreateAccount;
-use pinocchio_token::state::TokenAccount;
+use pinocchio_token::{
+    instructions::TransferChecked,
+    state::{Mint, TokenAccount},
+};
+use pinocchio_associated_token_account::instructions::Create;
 use crate::{
     errors::SwapError,
+    events::{emit, SwapCreated},
+    validation::{check_program_owner, check_signer, check_writable},
     states::{
-        utils::{load_ix_data, DataLen},
+        utils::{load_acc, load_ix_data, DataLen},
+        Config,
         SwapState,
+        MAX_PRICE_EXPONENT,
+        MIN_PRICE_EXPONENT,
     },
 };
+use super::set_fee_config::MAX_FEE_BPS;
+
+/// Quote bonuses stay below 100% less the highest protocol fee, so part of every `quote_in`
+/// still reaches the pool. Scaled like the bonus rates, where 100% = 100_000_000_000.
+pub const BONUS_QUOTE_CAP: u64 = 100_000_000_000 - MAX_FEE_BPS as u64 * 10_000_000;
 
 #[repr(C, packed)]
 #[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
@@ -24,6 +38,21 @@ pub struct CreateData {
     pub price: u64,
     pub bonus_base: u64,
     pub bonus_quote: u64,
+    /// Cap on per-referrer base bonus rates, must be at least `bonus_base`.
+    pub bonus_base_max: u64,
+    /// Cap on per-referrer quote bonus rates, must be at least `bonus_quote` and below
+    /// `BONUS_QUOTE_CAP`.
+    pub bonus_quote_max: u64,
+    /// Seconds base bonuses stay locked before they can be claimed (0 = paid instantly).
+    pub bonus_lock_period: i64,
+    /// 1 if referrers must be approved by the owner before earning bonuses.
+    pub referrer_approval: u8,
+    /// 1 to create the pool's base vault as its associated token account.
+    pub create_vault: u8,
+    /// Base tokens moved from the owner into the vault on creation.
+    pub initial_deposit: u64,
+    /// Power of ten `price` is multiplied by, for prices a 1e9-scaled u64 can't hold.
+    pub price_exponent: i8,
     pub bump_seed: u8,
 }
 
@@ -41,32 +70,89 @@ pub fn create(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
         swap_acc,
         base_acc,
         quote_acc,
-        _system_program,
-        rent_acc
+        system_program_acc,
+        rent_acc,
+        config_acc,
+        vault_accs @ ..
     ] = accounts else {
         return Err(SwapError::NotEnoughAccountKeysCreate.into());
     };
-    if !owner_acc.is_signer() {
-        return Err(SwapError::MissingRequiredSignatureCreate.into());
+    // Creating or funding the base vault needs the base mint, the owner's base
+    // token account, the token program and the associated token program
+    let vault_accs: Option<&[AccountInfo; 4]> = if ix_data.create_vault != 0 || ix_data.initial_deposit > 0 {
+        Some(vault_accs.try_into().map_err(|_| SwapError::NotEnoughAccountKeysCreateVault)?)
+    } else {
+        None
+    };
+    check_signer(owner_acc, SwapError::MissingRequiredSignatureCreate)?;
+    check_writable(&[owner_acc, swap_acc], SwapError::NotWritableCreate)?;
+    check_program_owner(&[config_acc], SwapError::WrongProgramOwnerCreate)?;
+    let config_state = unsafe { load_acc::<Config>(config_acc) }?;
+    Config::validate_pda(config_state.bump_seed, config_acc.key())?;
+    if config_state.paused {
+        return Err(SwapError::ProgramPausedCreate.into());
+    }
+    // New pools are namespaced by owner so uuids cannot be squatted, and must use
+    // the canonical bump so indexers can derive their addresses
+    let (pda, canonical_bump) = SwapState::find_pda(owner_acc.key(), ix_data.uuid);
+    if pda != *swap_acc.key() {
+        return Err(SwapError::InvalidPDAValidation.into());
+    }
+    if ix_data.bump_seed != canonical_bump {
+        return Err(SwapError::NonCanonicalBumpCreate.into());
     }
-    SwapState::validate_pda(ix_data.bump_seed, ix_data.uuid, swap_acc.key())?;
     if !swap_acc.data_is_empty() {
         return Err(SwapError::AccountAlreadyInitializedCreate.into());
     }
+
+    // Create the base vault as the pool PDA's associated token account
+    if ix_data.create_vault != 0 {
+        if let Some([base_mint_acc, _, token_program_acc, _]) = vault_accs {
+            log!("Create base vault");
+            Create {
+                funding_account: owner_acc,
+                account: base_acc,
+                wallet: swap_acc,
+                mint: base_mint_acc,
+                system_program: system_program_acc,
+                token_program: token_program_acc,
+            }
+            .invoke()?;
+        }
+    }
+
     let base_token = TokenAccount::from_account_info(base_acc)?;
     let quote_token = TokenAccount::from_account_info(quote_acc)?;
+    let base_mint = *base_token.mint();
     if base_token.mint() == quote_token.mint() {
         return Err(SwapError::SameMintCreate.into());
     }
+    if !config_state.is_quote_mint_allowed(quote_token.mint()) {
+        return Err(SwapError::QuoteMintNotAllowedCreate.into());
+    }
     if base_token.owner() != swap_acc.key() {
         return Err(SwapError::WrongOwnerBaseCreate.into());
     }
     if quote_token.owner() == swap_acc.key() {
         return Err(SwapError::WrongOwnerQuoteCreate.into());
     }
+    // Release the vault borrow before the deposit transfer below
+    drop(base_token);
     if ix_data.price == 0 {
         return Err(SwapError::InvalidParametersCreatePrice.into());
     }
+    if !(MIN_PRICE_EXPONENT..=MAX_PRICE_EXPONENT).contains(&ix_data.price_exponent) {
+        return Err(SwapError::InvalidParametersCreatePriceExponent.into());
+    }
+    if ix_data.bonus_base > ix_data.bonus_base_max
+        || ix_data.bonus_quote > ix_data.bonus_quote_max
+        || ix_data.bonus_quote_max >= BONUS_QUOTE_CAP
+    {
+        return Err(SwapError::InvalidParametersCreateBonusMax.into());
+    }
+    if ix_data.bonus_lock_period < 0 {
+        return Err(SwapError::InvalidParametersCreateBonusLockPeriod.into());
+    }
     let mut quote_sol: bool = false;
     let quote_owner = *quote_token.owner();
 
@@ -77,6 +163,8 @@ pub fn create(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     let uuid_binding = ix_data.uuid.to_le_bytes();
     let pda_bump_bytes = [ix_data.bump_seed];
     let signer_seeds = [
+        Seed::from(SwapState::SEED),
+        Seed::from(owner_acc.key()),
         Seed::from(&uuid_binding),
         Seed::from(&pda_bump_bytes[..]),
     ];
@@ -91,6 +179,38 @@ pub fn create(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     }
     .invoke_signed(&signers)?;
     SwapState::create_swap(swap_acc, owner_acc, base_acc, quote_acc, ix_data, quote_sol, quote_owner)?;
+
+    // Fund the base vault from the owner
+    if ix_data.initial_deposit > 0 {
+        if let Some([base_mint_acc, owner_base_acc, _, _]) = vault_accs {
+            if *base_mint_acc.key() != base_mint {
+                return Err(SwapError::WrongMintBaseCreate.into());
+            }
+            let base_decimals = Mint::from_account_info(base_mint_acc)?.decimals();
+            log!("Transfer base token from owner to vault: {}", ix_data.initial_deposit);
+            TransferChecked {
+                from: owner_base_acc,
+                mint: base_mint_acc,
+                to: base_acc,
+                authority: owner_acc,
+                amount: ix_data.initial_deposit,
+                decimals: base_decimals,
+            }
+            .invoke()?;
+        }
+    }
+    emit(&SwapCreated {
+        swap: *swap_acc.key(),
+        owner: *owner_acc.key(),
+        vault_base: *base_acc.key(),
+        vault_quote: if quote_sol { quote_owner } else { *quote_acc.key() },
+        uuid: ix_data.uuid,
+        price: ix_data.price,
+        bonus_base: ix_data.bonus_base,
+        bonus_quote: ix_data.bonus_quote,
+        initial_deposit: ix_data.initial_deposit,
+        price_exponent: ix_data.price_exponent,
+    });
     log!("Swap Created");
     Ok(())
 }
@@ -0,0 +1,157 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use shank::ShankAccount;
+use pinocchio_log::log;
+use pinocchio_system::instructions::CreateAccount;
+use pinocchio_token::{
+    instructions::TransferChecked,
+    state::{Mint, TokenAccount},
+};
+use pinocchio_associated_token_account::instructions::Create;
+use crate::{
+    errors::SwapError,
+    events::{emit, BuyOrderCreated},
+    validation::{check_program_owner, check_signer, check_writable},
+    states::{
+        utils::{load_acc, load_ix_data, DataLen},
+        BuyOrder,
+        Config,
+    },
+};
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct CreateBuyOrderData {
+    pub uuid: u128,
+    /// 1e9-scaled limit price of 1 base in quote.
+    pub price: u64,
+    /// Quote tokens moved from the buyer into escrow.
+    pub quote_amount: u64,
+}
+
+impl DataLen for CreateBuyOrderData {
+    const LEN: usize = core::mem::size_of::<CreateBuyOrderData>();
+}
+
+/// Escrow quote in a new buy order PDA derived from `["buy", buyer, uuid]`. Its quote
+/// vault is created as the order's associated token account.
+pub fn create_buy_order(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    log!("Create Buy Order");
+    let ix_data = unsafe { load_ix_data::<CreateBuyOrderData>(data)? };
+    let [
+        buyer_acc,
+        order_acc,
+        vault_quote_acc,
+        buyer_quote_acc,
+        buyer_base_acc,
+        base_mint_acc,
+        quote_mint_acc,
+        config_acc,
+        system_program_acc,
+        token_program_acc,
+        _ata_program_acc,
+    ] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysCreateBuyOrder.into());
+    };
+
+    check_signer(buyer_acc, SwapError::MissingRequiredSignatureCreateBuyOrder)?;
+    check_writable(&[buyer_acc, order_acc, vault_quote_acc, buyer_quote_acc], SwapError::NotWritableCreateBuyOrder)?;
+    check_program_owner(&[config_acc], SwapError::WrongProgramOwnerCreateBuyOrder)?;
+
+    let config_state = unsafe { load_acc::<Config>(config_acc) }?;
+    Config::validate_pda(config_state.bump_seed, config_acc.key())?;
+    if config_state.paused {
+        return Err(SwapError::ProgramPausedCreateBuyOrder.into());
+    }
+    if base_mint_acc.key() == quote_mint_acc.key() {
+        return Err(SwapError::SameMintCreateBuyOrder.into());
+    }
+    if !config_state.is_quote_mint_allowed(quote_mint_acc.key()) {
+        return Err(SwapError::QuoteMintNotAllowedCreateBuyOrder.into());
+    }
+    if ix_data.price == 0 || ix_data.quote_amount == 0 {
+        return Err(SwapError::InvalidParametersCreateBuyOrder.into());
+    }
+    let buyer_base = TokenAccount::from_account_info(buyer_base_acc)?;
+    if buyer_base.mint() != base_mint_acc.key() {
+        return Err(SwapError::WrongMintBaseCreateBuyOrder.into());
+    }
+    drop(buyer_base);
+
+    let (pda, bump) = BuyOrder::find_pda(buyer_acc.key(), ix_data.uuid);
+    if pda != *order_acc.key() {
+        return Err(SwapError::InvalidPDAValidation.into());
+    }
+    if !order_acc.data_is_empty() {
+        return Err(SwapError::AccountAlreadyInitializedCreateBuyOrder.into());
+    }
+
+    let uuid_binding = ix_data.uuid.to_le_bytes();
+    let pda_bump_bytes = [bump];
+    let signer_seeds = [
+        Seed::from(BuyOrder::SEED),
+        Seed::from(buyer_acc.key()),
+        Seed::from(&uuid_binding),
+        Seed::from(&pda_bump_bytes[..]),
+    ];
+    let signers = [Signer::from(&signer_seeds[..])];
+    let rent = Rent::get()?;
+    CreateAccount {
+        from: buyer_acc,
+        to: order_acc,
+        space: BuyOrder::LEN as u64,
+        owner: &crate::ID,
+        lamports: rent.minimum_balance(BuyOrder::LEN),
+    }
+    .invoke_signed(&signers)?;
+
+    Create {
+        funding_account: buyer_acc,
+        account: vault_quote_acc,
+        wallet: order_acc,
+        mint: quote_mint_acc,
+        system_program: system_program_acc,
+        token_program: token_program_acc,
+    }
+    .invoke()?;
+
+    let quote_decimals = Mint::from_account_info(quote_mint_acc)?.decimals();
+    log!("Transfer quote from buyer to escrow: {}", ix_data.quote_amount);
+    TransferChecked {
+        from: buyer_quote_acc,
+        mint: quote_mint_acc,
+        to: vault_quote_acc,
+        authority: buyer_acc,
+        amount: ix_data.quote_amount,
+        decimals: quote_decimals,
+    }
+    .invoke()?;
+
+    BuyOrder::create_buy_order(
+        order_acc,
+        buyer_acc,
+        base_mint_acc,
+        quote_mint_acc,
+        vault_quote_acc,
+        buyer_base_acc,
+        ix_data.uuid,
+        ix_data.price,
+        ix_data.quote_amount,
+        bump,
+    )?;
+
+    emit(&BuyOrderCreated {
+        order: *order_acc.key(),
+        buyer: *buyer_acc.key(),
+        base_mint: *base_mint_acc.key(),
+        quote_mint: *quote_mint_acc.key(),
+        price: ix_data.price,
+        quote_amount: ix_data.quote_amount,
+    });
+    log!("Buy Order Created");
+    Ok(())
+}
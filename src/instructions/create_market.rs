@@ -0,0 +1,93 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_log::log;
+use pinocchio_system::instructions::CreateAccount;
+use pinocchio_associated_token_account::instructions::Create;
+use crate::{
+    errors::SwapError,
+    validation::{check_program_owner, check_signer, check_writable},
+    states::{
+        utils::{load_acc, DataLen},
+        Config,
+        Market,
+    },
+};
+
+/// Create the market for a (base, quote) pair and its base vault, the market PDA's
+/// associated token account. Anyone may create a market; sellers then post offers.
+pub fn create_market(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    log!("Create Market");
+    let [
+        payer_acc,
+        market_acc,
+        vault_base_acc,
+        base_mint_acc,
+        quote_mint_acc,
+        config_acc,
+        system_program_acc,
+        token_program_acc,
+        _ata_program_acc,
+    ] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysCreateMarket.into());
+    };
+
+    check_signer(payer_acc, SwapError::MissingRequiredSignatureCreateMarket)?;
+    check_writable(&[payer_acc, market_acc, vault_base_acc], SwapError::NotWritableCreateMarket)?;
+    check_program_owner(&[config_acc], SwapError::WrongProgramOwnerCreateMarket)?;
+
+    let config_state = unsafe { load_acc::<Config>(config_acc) }?;
+    Config::validate_pda(config_state.bump_seed, config_acc.key())?;
+    if config_state.paused {
+        return Err(SwapError::ProgramPausedCreateMarket.into());
+    }
+    if base_mint_acc.key() == quote_mint_acc.key() {
+        return Err(SwapError::SameMintCreateMarket.into());
+    }
+    if !config_state.is_quote_mint_allowed(quote_mint_acc.key()) {
+        return Err(SwapError::QuoteMintNotAllowedCreateMarket.into());
+    }
+
+    let (pda, bump) = Market::find_pda(base_mint_acc.key(), quote_mint_acc.key());
+    if pda != *market_acc.key() {
+        return Err(SwapError::InvalidPDAValidation.into());
+    }
+    if !market_acc.data_is_empty() {
+        return Err(SwapError::AccountAlreadyInitializedCreateMarket.into());
+    }
+
+    let pda_bump_bytes = [bump];
+    let signer_seeds = [
+        Seed::from(Market::SEED),
+        Seed::from(base_mint_acc.key()),
+        Seed::from(quote_mint_acc.key()),
+        Seed::from(&pda_bump_bytes[..]),
+    ];
+    let signers = [Signer::from(&signer_seeds[..])];
+    let rent = Rent::get()?;
+    CreateAccount {
+        from: payer_acc,
+        to: market_acc,
+        space: Market::LEN as u64,
+        owner: &crate::ID,
+        lamports: rent.minimum_balance(Market::LEN),
+    }
+    .invoke_signed(&signers)?;
+
+    Create {
+        funding_account: payer_acc,
+        account: vault_base_acc,
+        wallet: market_acc,
+        mint: base_mint_acc,
+        system_program: system_program_acc,
+        token_program: token_program_acc,
+    }
+    .invoke()?;
+
+    Market::create_market(market_acc, base_mint_acc, quote_mint_acc, vault_base_acc, bump)?;
+    log!("Market Created");
+    Ok(())
+}
@@ -0,0 +1,184 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::set_return_data,
+    instruction::Signer,
+    ProgramResult,
+};
+use shank::ShankAccount;
+use pinocchio_log::log;
+use pinocchio_token::{
+    instructions::TransferChecked,
+    state::{Mint, TokenAccount},
+};
+use crate::{
+    errors::SwapError,
+    events::{emit, BuyOrderFilled},
+    validation::{check_program_owner, check_signer, check_writable},
+    states::{
+        utils::{load_acc, load_acc_mut, load_ix_data, to_bytes, DataLen},
+        BuyOrder,
+        BuyOrderSeeds,
+        Config,
+        Rounding,
+    },
+};
+use super::swap::{swap_amounts, Price, SwapResult};
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct FillData {
+    /// Escrowed quote taken from the order, protocol fee included.
+    pub quote_out: u64,
+    /// Most base tokens the seller is willing to deliver.
+    pub max_base_in: u64,
+}
+
+impl DataLen for FillData {
+    const LEN: usize = core::mem::size_of::<FillData>();
+}
+
+/// Sell base into a buy order at its limit price. The seller delivers base straight to
+/// the buyer, `quote_out / price` rounded up, and is paid from the order's quote vault,
+/// minus the protocol fee.
+///
+/// `fee_treasury_acc` follows the fixed accounts when the protocol fee is set.
+pub fn fill(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    log!("Fill");
+    let ix_data = unsafe { load_ix_data::<FillData>(data)? };
+    let [
+        seller_acc,
+        order_acc,
+        vault_quote_acc,
+        seller_base_acc,
+        seller_quote_acc,
+        buyer_base_acc,
+        base_mint_acc,
+        quote_mint_acc,
+        _token_program,
+        config_acc,
+        rest @ ..
+    ] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysFill.into());
+    };
+
+    check_signer(seller_acc, SwapError::MissingRequiredSignatureFill)?;
+    check_writable(&[order_acc, vault_quote_acc, seller_base_acc, seller_quote_acc, buyer_base_acc], SwapError::NotWritableFill)?;
+    check_program_owner(&[order_acc, config_acc], SwapError::WrongProgramOwnerFill)?;
+    if ix_data.quote_out == 0 {
+        return Err(SwapError::InvalidInstructionDataFillQuoteOutZero.into());
+    }
+
+    let config_state = unsafe { load_acc::<Config>(config_acc) }?;
+    Config::validate_pda(config_state.bump_seed, config_acc.key())?;
+    if config_state.paused {
+        return Err(SwapError::ProgramPausedFill.into());
+    }
+    let fee_treasury_acc = match rest {
+        [fee_treasury_acc, ..] if config_state.fee_bps != 0 => {
+            let treasury_ata = TokenAccount::from_account_info(fee_treasury_acc)?;
+            if *treasury_ata.owner() != config_state.fee_treasury {
+                return Err(SwapError::WrongTreasuryFill.into());
+            }
+            Some(fee_treasury_acc)
+        }
+        [] if config_state.fee_bps != 0 => return Err(SwapError::NotEnoughAccountKeysFill.into()),
+        _ => None,
+    };
+
+    let order_state = unsafe { load_acc_mut::<BuyOrder>(order_acc) }?;
+    BuyOrder::validate_pda(order_state.bump_seed, &order_state.buyer, order_state.uuid, order_acc.key())?;
+    if order_state.vault_quote != *vault_quote_acc.key() {
+        return Err(SwapError::WrongVaultQuoteFill.into());
+    }
+    if order_state.buyer_base != *buyer_base_acc.key() {
+        return Err(SwapError::WrongBuyerBaseFill.into());
+    }
+    if order_state.base_mint != *base_mint_acc.key() {
+        return Err(SwapError::WrongMintBaseFill.into());
+    }
+    if order_state.quote_mint != *quote_mint_acc.key() {
+        return Err(SwapError::WrongMintQuoteFill.into());
+    }
+    if ix_data.quote_out > order_state.quote_remaining {
+        return Err(SwapError::InsufficientQuoteFill.into());
+    }
+    let base_decimals = Mint::from_account_info(base_mint_acc)?.decimals();
+    let quote_decimals = Mint::from_account_info(quote_mint_acc)?.decimals();
+
+    // Round the delivered base up so the buyer never pays above their limit price
+    let amounts = swap_amounts(
+        Price::scaled(order_state.price),
+        config_state.fee_bps,
+        0,
+        0,
+        ix_data.quote_out,
+        base_decimals,
+        quote_decimals,
+        Rounding::Ceil,
+    )?;
+    if amounts.base_out > ix_data.max_base_in {
+        return Err(SwapError::SlippageFill.into());
+    }
+
+    let order_seeds = BuyOrderSeeds::new(order_state);
+    let signer_seeds = order_seeds.seeds();
+    let signers = [Signer::from(&signer_seeds[..])];
+
+    log!("Transfer base token from seller to buyer: {}", amounts.base_out);
+    TransferChecked {
+        from: seller_base_acc,
+        mint: base_mint_acc,
+        to: buyer_base_acc,
+        authority: seller_acc,
+        amount: amounts.base_out,
+        decimals: base_decimals,
+    }
+    .invoke()?;
+    log!("Transfer quote from escrow to seller: {}", amounts.quote_to_vault);
+    TransferChecked {
+        from: vault_quote_acc,
+        mint: quote_mint_acc,
+        to: seller_quote_acc,
+        authority: order_acc,
+        amount: amounts.quote_to_vault,
+        decimals: quote_decimals,
+    }
+    .invoke_signed(&signers)?;
+    if amounts.protocol_fee > 0 {
+        let fee_treasury_acc = fee_treasury_acc.ok_or(SwapError::NotEnoughAccountKeysFill)?;
+        log!("Transfer quote from escrow to treasury: {}", amounts.protocol_fee);
+        TransferChecked {
+            from: vault_quote_acc,
+            mint: quote_mint_acc,
+            to: fee_treasury_acc,
+            authority: order_acc,
+            amount: amounts.protocol_fee,
+            decimals: quote_decimals,
+        }
+        .invoke_signed(&signers)?;
+    }
+
+    let quote_remaining = order_state.quote_remaining - ix_data.quote_out;
+    order_state.quote_remaining = quote_remaining;
+    emit(&BuyOrderFilled {
+        order: *order_acc.key(),
+        seller: *seller_acc.key(),
+        price: order_state.price,
+        base_in: amounts.base_out,
+        quote_out: amounts.quote_to_vault,
+        protocol_fee: amounts.protocol_fee,
+        quote_remaining,
+    });
+
+    let result = SwapResult {
+        base_out: amounts.base_out,
+        quote_to_vault: amounts.quote_to_vault,
+        quote_bonus: 0,
+        base_bonus: 0,
+        volume_tier: 0,
+        discount_bps: 0,
+    };
+    set_return_data(unsafe { to_bytes(&result) });
+    log!("Fill Completed: {}", amounts.base_out);
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    pubkey::{find_program_address, Pubkey},
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_log::log;
+use pinocchio_system::instructions::CreateAccount;
+use crate::{
+    errors::SwapError,
+    events::{emit, ConfigUpdated},
+    validation::{check_signer, check_writable},
+    states::{
+        utils::{load_acc, DataLen},
+        Config,
+    },
+};
+
+const BPF_LOADER_UPGRADEABLE_ID: Pubkey = pinocchio_pubkey::pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+
+/// Create the program config. Run once after deployment by the program's upgrade
+/// authority, who becomes the admin, so the config cannot be front-run.
+pub fn init_config(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    log!("Init Config");
+    let [admin_acc, config_acc, _system_program, program_data_acc] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysInitConfig.into());
+    };
+
+    // Validate admin is signer
+    check_signer(admin_acc, SwapError::MissingRequiredSignatureInitConfig)?;
+    check_writable(&[admin_acc, config_acc], SwapError::NotWritableInitConfig)?;
+    check_upgrade_authority(program_data_acc, admin_acc)?;
+
+    let (pda, bump) = Config::find_pda();
+    if pda != *config_acc.key() {
+        return Err(SwapError::InvalidPDAValidation.into());
+    }
+    if !config_acc.data_is_empty() {
+        return Err(SwapError::AccountAlreadyInitializedInitConfig.into());
+    }
+
+    let pda_bump_bytes = [bump];
+    let signer_seeds = [
+        Seed::from(Config::SEED),
+        Seed::from(&pda_bump_bytes[..]),
+    ];
+    let signers = [Signer::from(&signer_seeds[..])];
+    let rent = Rent::get()?;
+    CreateAccount {
+        from: admin_acc,
+        to: config_acc,
+        space: Config::LEN as u64,
+        owner: &crate::ID,
+        lamports: rent.minimum_balance(Config::LEN),
+    }
+    .invoke_signed(&signers)?;
+    Config::create_config(config_acc, admin_acc, bump)?;
+    emit(&ConfigUpdated::new(unsafe { load_acc::<Config>(config_acc) }?));
+
+    log!("Config Initialized");
+    Ok(())
+}
+
+/// The signer must be the upgrade authority recorded in this program's ProgramData
+/// account: a `u32` state tag (3), the deploy slot, then an `Option<Pubkey>` authority.
+fn check_upgrade_authority(program_data_acc: &AccountInfo, admin_acc: &AccountInfo) -> ProgramResult {
+    let (program_data, _) = find_program_address(&[crate::ID.as_ref()], &BPF_LOADER_UPGRADEABLE_ID);
+    if *program_data_acc.key() != program_data || !program_data_acc.is_owned_by(&BPF_LOADER_UPGRADEABLE_ID) {
+        return Err(SwapError::WrongProgramDataInitConfig.into());
+    }
+    let data = program_data_acc.try_borrow_data()?;
+    if data.len() < 45 || data[..4] != 3u32.to_le_bytes() {
+        return Err(SwapError::WrongProgramDataInitConfig.into());
+    }
+    if data[12] != 1 || data[13..45] != admin_acc.key()[..] {
+        return Err(SwapError::NotUpgradeAuthorityInitConfig.into());
+    }
+    Ok(())
+}
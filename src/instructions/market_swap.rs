@@ -0,0 +1,215 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::set_return_data,
+    instruction::{Seed, Signer},
+    ProgramResult,
+};
+use shank::ShankAccount;
+use pinocchio_log::log;
+use pinocchio_token::{
+    instructions::TransferChecked,
+    state::{Mint, TokenAccount},
+};
+use crate::{
+    errors::SwapError,
+    events::{emit, OfferFilled},
+    validation::{check_program_owner, check_signer, check_writable},
+    states::{
+        utils::{load_acc, load_acc_mut, load_ix_data, to_bytes, DataLen},
+        Config,
+        Market,
+        Rounding,
+        MAX_MARKET_OFFERS,
+    },
+};
+use super::{
+    batch_swap::max_quote_in,
+    swap::{swap_amounts, Price, SwapResult},
+};
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct MarketSwapData {
+    /// Quote tokens the user is willing to spend.
+    pub quote_in: u64,
+    /// Least base tokens the user accepts.
+    pub min_base_out: u64,
+}
+
+impl DataLen for MarketSwapData {
+    const LEN: usize = core::mem::size_of::<MarketSwapData>();
+}
+
+/// Buy from a market's offers, cheapest first, until the quote budget is spent.
+///
+/// After the fixed accounts come `fee_treasury_acc` when the protocol fee is set, then
+/// the quote token accounts of the sellers to buy from. Offers whose seller account is
+/// not passed are skipped.
+pub fn market_swap(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    log!("Market Swap");
+    let ix_data = unsafe { load_ix_data::<MarketSwapData>(data)? };
+    let [
+        user_acc,
+        market_acc,
+        vault_base_acc,
+        user_base_acc,
+        user_quote_acc,
+        base_mint_acc,
+        quote_mint_acc,
+        _token_program,
+        config_acc,
+        rest @ ..
+    ] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysMarketSwap.into());
+    };
+
+    check_signer(user_acc, SwapError::MissingRequiredSignatureMarketSwap)?;
+    check_writable(&[market_acc, vault_base_acc, user_base_acc, user_quote_acc], SwapError::NotWritableMarketSwap)?;
+    check_program_owner(&[market_acc, config_acc], SwapError::WrongProgramOwnerMarketSwap)?;
+    if ix_data.quote_in == 0 {
+        return Err(SwapError::InvalidInstructionDataMarketSwapQuoteInZero.into());
+    }
+
+    let config_state = unsafe { load_acc::<Config>(config_acc) }?;
+    Config::validate_pda(config_state.bump_seed, config_acc.key())?;
+    if config_state.paused {
+        return Err(SwapError::ProgramPausedMarketSwap.into());
+    }
+    let (fee_treasury_acc, seller_quote_accs) = match rest {
+        [fee_treasury_acc, seller_quote_accs @ ..] if config_state.fee_bps != 0 => (Some(fee_treasury_acc), seller_quote_accs),
+        [] if config_state.fee_bps != 0 => return Err(SwapError::NotEnoughAccountKeysMarketSwap.into()),
+        _ => (None, rest),
+    };
+    if let Some(fee_treasury_acc) = fee_treasury_acc {
+        let treasury_ata = TokenAccount::from_account_info(fee_treasury_acc)?;
+        if *treasury_ata.owner() != config_state.fee_treasury {
+            return Err(SwapError::WrongTreasuryMarketSwap.into());
+        }
+    }
+
+    let market_state = unsafe { load_acc_mut::<Market>(market_acc) }?;
+    Market::validate_pda(market_state.bump_seed, &market_state.base_mint, &market_state.quote_mint, market_acc.key())?;
+    if market_state.vault_base != *vault_base_acc.key() {
+        return Err(SwapError::WrongVaultBaseMarketSwap.into());
+    }
+    if market_state.base_mint != *base_mint_acc.key() {
+        return Err(SwapError::WrongMintBaseMarketSwap.into());
+    }
+    if market_state.quote_mint != *quote_mint_acc.key() {
+        return Err(SwapError::WrongMintQuoteMarketSwap.into());
+    }
+    let base_decimals = Mint::from_account_info(base_mint_acc)?.decimals();
+    let quote_decimals = Mint::from_account_info(quote_mint_acc)?.decimals();
+
+    let base_mint = market_state.base_mint;
+    let quote_mint = market_state.quote_mint;
+    let pda_bump_bytes = [market_state.bump_seed];
+    let signer_seeds = [
+        Seed::from(Market::SEED),
+        Seed::from(&base_mint),
+        Seed::from(&quote_mint),
+        Seed::from(&pda_bump_bytes[..]),
+    ];
+    let signers = [Signer::from(&signer_seeds[..])];
+
+    // Cheapest open offers first, earliest slot first on equal prices
+    let mut order = [0usize; MAX_MARKET_OFFERS];
+    let mut open_count = 0;
+    for (i, offer) in market_state.offers.iter().enumerate() {
+        if offer.is_open() {
+            order[open_count] = i;
+            open_count += 1;
+        }
+    }
+    order[..open_count].sort_unstable_by_key(|&i| (market_state.offers[i].price, i));
+
+    let mut quote_remaining = ix_data.quote_in;
+    let mut total_base_out: u64 = 0;
+    let mut total_quote_to_sellers: u64 = 0;
+    for &i in &order[..open_count] {
+        if quote_remaining == 0 {
+            break;
+        }
+        let offer = market_state.offers[i];
+        let Some(seller_quote_acc) = seller_quote_accs.iter().find(|acc| *acc.key() == offer.seller_quote) else {
+            continue;
+        };
+        let quote_in = quote_remaining.min(max_quote_in(offer.base_remaining, Price::scaled(offer.price), base_decimals, quote_decimals, Rounding::Floor));
+        // Too little quote left to buy a single base unit from this offer
+        let Ok(amounts) = swap_amounts(Price::scaled(offer.price), config_state.fee_bps, 0, 0, quote_in, base_decimals, quote_decimals, Rounding::Floor) else {
+            continue;
+        };
+
+        log!("Transfer quote from user to seller: {}", amounts.quote_to_vault);
+        TransferChecked {
+            from: user_quote_acc,
+            mint: quote_mint_acc,
+            to: seller_quote_acc,
+            authority: user_acc,
+            amount: amounts.quote_to_vault,
+            decimals: quote_decimals,
+        }
+        .invoke()?;
+        if amounts.protocol_fee > 0 {
+            let fee_treasury_acc = fee_treasury_acc.ok_or(SwapError::NotEnoughAccountKeysMarketSwap)?;
+            log!("Transfer quote from user to treasury: {}", amounts.protocol_fee);
+            TransferChecked {
+                from: user_quote_acc,
+                mint: quote_mint_acc,
+                to: fee_treasury_acc,
+                authority: user_acc,
+                amount: amounts.protocol_fee,
+                decimals: quote_decimals,
+            }
+            .invoke()?;
+        }
+        log!("Transfer base token from market vault to user: {}", amounts.base_out);
+        TransferChecked {
+            from: vault_base_acc,
+            mint: base_mint_acc,
+            to: user_base_acc,
+            authority: market_acc,
+            amount: amounts.base_out,
+            decimals: base_decimals,
+        }
+        .invoke_signed(&signers)?;
+
+        let base_remaining = offer.base_remaining - amounts.base_out;
+        market_state.offers[i].base_remaining = base_remaining;
+        emit(&OfferFilled {
+            market: *market_acc.key(),
+            offer_index: i as u8,
+            seller: offer.seller,
+            buyer: *user_acc.key(),
+            price: offer.price,
+            quote_in: amounts.quote_in,
+            base_out: amounts.base_out,
+            protocol_fee: amounts.protocol_fee,
+            base_remaining,
+        });
+
+        quote_remaining -= quote_in;
+        total_base_out = total_base_out
+            .checked_add(amounts.base_out)
+            .ok_or(SwapError::InvalidParametersStatsOverflow)?;
+        total_quote_to_sellers = total_quote_to_sellers
+            .checked_add(amounts.quote_to_vault)
+            .ok_or(SwapError::InvalidParametersStatsOverflow)?;
+    }
+
+    if total_base_out < ix_data.min_base_out {
+        return Err(SwapError::SlippageMarketSwap.into());
+    }
+
+    let result = SwapResult {
+        base_out: total_base_out,
+        quote_to_vault: total_quote_to_sellers,
+        quote_bonus: 0,
+        base_bonus: 0,
+        volume_tier: 0,
+        discount_bps: 0,
+    };
+    set_return_data(unsafe { to_bytes(&result) });
+    log!("Market Swap Completed: {}", total_base_out);
+    Ok(())
+}
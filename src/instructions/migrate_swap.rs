@@ -0,0 +1,74 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_log::log;
+use pinocchio_system::instructions::Transfer;
+use crate::{
+    errors::SwapError,
+    events::{emit, SwapMigrated},
+    validation::{check_program_owner, check_signer, check_writable},
+    states::{
+        utils::{load_acc_mut_unchecked, load_acc_unchecked, DataLen, Discriminator},
+        LegacySwapState,
+        SwapState,
+    },
+};
+
+/// Upgrade a legacy pool, created before accounts carried a discriminator and version,
+/// to the current `SwapState` layout.
+pub fn migrate_swap(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    log!("Migrate Swap");
+    let [owner_acc, swap_acc, _system_program] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysMigrate.into());
+    };
+
+    // Validate owner is signer
+    check_signer(owner_acc, SwapError::MissingRequiredSignatureMigrate)?;
+    check_writable(&[owner_acc, swap_acc], SwapError::NotWritableMigrate)?;
+    check_program_owner(&[swap_acc], SwapError::WrongProgramOwnerMigrate)?;
+
+    // Only legacy pools migrate
+    if swap_acc.data_len() != LegacySwapState::LEN {
+        let data = unsafe { swap_acc.borrow_data_unchecked() };
+        if data.len() < 2 || data[0] != SwapState::DISCRIMINATOR {
+            return Err(SwapError::InvalidAccountDataDiscriminator.into());
+        }
+        if data[1] == SwapState::VERSION {
+            return Err(SwapError::AlreadyMigratedMigrate.into());
+        }
+        return Err(SwapError::InvalidAccountDataVersion.into());
+    }
+
+    // Copy the legacy state out before the account is resized
+    let legacy = unsafe { *load_acc_unchecked::<LegacySwapState>(swap_acc.borrow_data_unchecked())? };
+
+    // Top up rent for the larger layout
+    let rent = Rent::get()?;
+    let required = rent.minimum_balance(SwapState::LEN);
+    let current = swap_acc.lamports();
+    if required > current {
+        Transfer {
+            from: owner_acc,
+            to: swap_acc,
+            lamports: required - current,
+        }
+        .invoke()?;
+    }
+
+    swap_acc.resize(SwapState::LEN)?;
+    SwapState::migrate_legacy(swap_acc, &legacy)?;
+    let swap_state = unsafe { load_acc_mut_unchecked::<SwapState>(swap_acc.borrow_mut_data_unchecked()) }?;
+    if swap_state.owner != *owner_acc.key() {
+        return Err(SwapError::NotOwnerMigrate.into());
+    }
+    swap_state.check_pda(swap_acc.key())?;
+
+    emit(&SwapMigrated {
+        swap: *swap_acc.key(),
+        version: SwapState::VERSION,
+    });
+    log!("Swap Migrated");
+    Ok(())
+}
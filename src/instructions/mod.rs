@@ -4,16 +4,82 @@ use crate::errors::SwapError;
 pub mod create;
 pub mod swap;
 pub mod close;
+pub mod claim_bonus;
+pub mod register_referrer;
+pub mod approve_referrer;
+pub mod set_referrer_rates;
+pub mod init_config;
+pub mod set_fee_config;
+pub mod update_config;
+pub mod migrate_swap;
+pub mod quote;
+pub mod batch_swap;
+pub mod route;
+pub mod rfq_swap;
+pub mod create_market;
+pub mod post_offer;
+pub mod cancel_offer;
+pub mod market_swap;
+pub mod create_buy_order;
+pub mod fill;
+pub mod cancel_buy_order;
+pub mod set_volume_tiers;
+pub mod set_rounding_policy;
+pub mod set_throttle;
 
 pub use create::*;
 pub use swap::*;
 pub use close::*;
+pub use claim_bonus::*;
+pub use register_referrer::*;
+pub use approve_referrer::*;
+pub use set_referrer_rates::*;
+pub use init_config::*;
+pub use set_fee_config::*;
+pub use update_config::*;
+pub use migrate_swap::*;
+pub use quote::*;
+pub use batch_swap::*;
+pub use route::*;
+pub use rfq_swap::*;
+pub use create_market::*;
+pub use post_offer::*;
+pub use cancel_offer::*;
+pub use market_swap::*;
+pub use create_buy_order::*;
+pub use fill::*;
+pub use cancel_buy_order::*;
+pub use set_volume_tiers::*;
+pub use set_rounding_policy::*;
+pub use set_throttle::*;
 
 #[repr(u8)]
 pub enum SwapProgramInstruction {
     Create,
     Swap,
     Close,
+    ClaimBonus,
+    RegisterReferrer,
+    ApproveReferrer,
+    SetReferrerRates,
+    InitConfig,
+    SetFeeConfig,
+    UpdateConfig,
+    MigrateSwap,
+    Quote,
+    BatchSwap,
+    Route,
+    RfqSwap,
+    CreateMarket,
+    PostOffer,
+    CancelOffer,
+    MarketSwap,
+    CreateBuyOrder,
+    Fill,
+    CancelBuyOrder,
+    SetVolumeTiers,
+    SetRoundingPolicy,
+    SetThrottle,
 }
 
 impl TryFrom<&u8> for SwapProgramInstruction {
@@ -24,6 +90,28 @@ impl TryFrom<&u8> for SwapProgramInstruction {
             0 => Ok(SwapProgramInstruction::Create),
             1 => Ok(SwapProgramInstruction::Swap),
             2 => Ok(SwapProgramInstruction::Close),
+            3 => Ok(SwapProgramInstruction::ClaimBonus),
+            4 => Ok(SwapProgramInstruction::RegisterReferrer),
+            5 => Ok(SwapProgramInstruction::ApproveReferrer),
+            6 => Ok(SwapProgramInstruction::SetReferrerRates),
+            7 => Ok(SwapProgramInstruction::InitConfig),
+            8 => Ok(SwapProgramInstruction::SetFeeConfig),
+            9 => Ok(SwapProgramInstruction::UpdateConfig),
+            10 => Ok(SwapProgramInstruction::MigrateSwap),
+            11 => Ok(SwapProgramInstruction::Quote),
+            12 => Ok(SwapProgramInstruction::BatchSwap),
+            13 => Ok(SwapProgramInstruction::Route),
+            14 => Ok(SwapProgramInstruction::RfqSwap),
+            15 => Ok(SwapProgramInstruction::CreateMarket),
+            16 => Ok(SwapProgramInstruction::PostOffer),
+            17 => Ok(SwapProgramInstruction::CancelOffer),
+            18 => Ok(SwapProgramInstruction::MarketSwap),
+            19 => Ok(SwapProgramInstruction::CreateBuyOrder),
+            20 => Ok(SwapProgramInstruction::Fill),
+            21 => Ok(SwapProgramInstruction::CancelBuyOrder),
+            22 => Ok(SwapProgramInstruction::SetVolumeTiers),
+            23 => Ok(SwapProgramInstruction::SetRoundingPolicy),
+            24 => Ok(SwapProgramInstruction::SetThrottle),
             _ => Err(SwapError::InvalidInstructionDataModTryFrom.into()),
         }
     }
@@ -31,39 +119,209 @@ impl TryFrom<&u8> for SwapProgramInstruction {
 
 mod idl_gen {
     use super::{
+        ApproveReferrerData,
+        BatchSwapData,
+        CancelOfferData,
+        CreateBuyOrderData,
         CreateData,
+        FillData,
+        MarketSwapData,
+        PostOfferData,
+        QuoteData,
+        RfqSwapData,
+        RouteData,
+        SetFeeConfigData,
+        SetReferrerRatesData,
+        SetRoundingPolicyData,
+        SetThrottleData,
+        SetVolumeTiersData,
         SwapData,
+        UpdateConfigData,
     };
 
+    #[allow(clippy::large_enum_variant)]
     #[derive(shank::ShankInstruction)]
     enum _SwapProgramInstruction {
         #[account(0, writable, signer, name = "owner_acc", desc = "Owner account")]
         #[account(1, writable, name = "swap_acc", desc = "Swap account")]
-        #[account(2, name = "vault_base_acc", desc = "Base vault")]
+        #[account(2, writable, name = "vault_base_acc", desc = "Base vault")]
         #[account(3, name = "vault_quote_acc", desc = "Quote vault")]
         #[account(4, name = "system_program")]
         #[account(5, name = "rent")]
+        #[account(6, name = "config_acc", desc = "Program config")]
+        #[account(7, optional, name = "base_mint_acc", desc = "Base mint (with create_vault or initial_deposit)")]
+        #[account(8, optional, writable, name = "owner_base_acc", desc = "Owner base token funding initial_deposit")]
+        #[account(9, optional, name = "token_program")]
+        #[account(10, optional, name = "ata_program")]
         Create(CreateData),
-        #[account(0, writable, signer, name = "user_acc", desc = "User account")]
-        #[account(1, name = "swap_acc", desc = "Swap account")]
+        #[account(0, signer, name = "user_acc", desc = "Authority over user_quote_acc (owner or delegate, may be a PDA)")]
+        #[account(1, writable, name = "swap_acc", desc = "Swap account")]
         #[account(2, writable, name = "vault_base_acc", desc = "Base vault")]
         #[account(3, writable, name = "vault_quote_acc", desc = "Quote vault")]
-        #[account(4, writable, name = "user_base_acc", desc = "User base token")]
+        #[account(4, writable, name = "user_base_acc", desc = "Base token account receiving base_out (any owner)")]
         #[account(5, writable, name = "user_quote_acc", desc = "User quote token")]
         #[account(6, name = "base_mint_acc", desc = "Base mint")]
         #[account(7, name = "quote_mint_acc", desc = "Quote mint")]
-        #[account(8, writable, name = "bonus_base_acc", desc = "Bonus base token")]
-        #[account(9, writable, name = "bonus_quote_acc", desc = "Bonus quote token or account")]
-        #[account(10, writable, name = "wsol_temp_acc", desc = "WSOL temporary token")]
-        #[account(11, name = "token_program")]
-        #[account(12, name = "system_program")]
-        #[account(13, name = "ata_program")]
+        #[account(8, name = "token_program")]
+        #[account(9, name = "config_acc", desc = "Program config")]
+        #[account(10, optional, writable, name = "wsol_temp_acc", desc = "WSOL temporary token (SOL-quoted pools only)")]
+        #[account(11, optional, name = "system_program", desc = "SOL-quoted pools only")]
+        #[account(12, optional, name = "ata_program", desc = "SOL-quoted pools only")]
+        #[account(13, optional, writable, name = "bonus_base_acc", desc = "Bonus base token (pools with bonuses only)")]
+        #[account(14, optional, writable, name = "bonus_quote_acc", desc = "Bonus quote token or account (pools with bonuses only)")]
+        #[account(15, optional, name = "referrer_acc", desc = "Referrer registration of the bonus recipient (pools with bonuses only)")]
+        #[account(16, optional, writable, name = "bonus_lock_acc", desc = "Bonus lock for bonus_base_acc (pools locking base bonuses only)")]
+        #[account(17, optional, name = "lock_system_program", desc = "Pools locking base bonuses only")]
+        #[account(18, optional, writable, name = "fee_treasury_acc", desc = "Fee treasury, quote token account or wallet for SOL (when fee_bps is set)")]
+        #[account(19, optional, writable, signer, name = "payer_acc", desc = "System account paying rent and relaying SOL (defaults to user_acc)")]
         Swap(SwapData),
         #[account(0, writable, signer, name = "owner_acc", desc = "Owner account")]
         #[account(1, writable, name = "swap_acc", desc = "Swap account")]
         #[account(2, writable, name = "vault_base_acc", desc = "Base vault")]
         #[account(3, writable, name = "owner_base_acc", desc = "Owner base token")]
-        #[account(4, name = "token_program")]
+        #[account(4, name = "base_mint_acc", desc = "Base mint")]
+        #[account(5, name = "token_program")]
+        #[account(6, writable, optional, name = "lock_accs", desc = "Outstanding bonus locks, each followed by its recipient base token, settled before closing")]
         Close,
+        #[account(0, writable, signer, name = "recipient_owner_acc", desc = "Owner of the bonus recipient token account")]
+        #[account(1, writable, name = "swap_acc", desc = "Swap account")]
+        #[account(2, writable, name = "bonus_lock_acc", desc = "Bonus lock")]
+        #[account(3, writable, name = "vault_base_acc", desc = "Base vault")]
+        #[account(4, writable, name = "recipient_base_acc", desc = "Bonus recipient base token")]
+        #[account(5, name = "base_mint_acc", desc = "Base mint")]
+        #[account(6, name = "token_program")]
+        ClaimBonus,
+        #[account(0, writable, signer, name = "wallet_acc", desc = "Referrer wallet")]
+        #[account(1, name = "swap_acc", desc = "Swap account")]
+        #[account(2, writable, name = "referrer_acc", desc = "Referrer registration")]
+        #[account(3, name = "system_program")]
+        RegisterReferrer,
+        #[account(0, signer, name = "owner_acc", desc = "Owner account")]
+        #[account(1, name = "swap_acc", desc = "Swap account")]
+        #[account(2, writable, name = "referrer_acc", desc = "Referrer registration")]
+        ApproveReferrer(ApproveReferrerData),
+        #[account(0, signer, name = "owner_acc", desc = "Owner account")]
+        #[account(1, name = "swap_acc", desc = "Swap account")]
+        #[account(2, writable, name = "referrer_acc", desc = "Referrer registration")]
+        SetReferrerRates(SetReferrerRatesData),
+        #[account(0, writable, signer, name = "admin_acc", desc = "Program upgrade authority, becomes the admin")]
+        #[account(1, writable, name = "config_acc", desc = "Program config")]
+        #[account(2, name = "system_program")]
+        #[account(3, name = "program_data_acc", desc = "ProgramData account of this program")]
+        InitConfig,
+        #[account(0, signer, name = "admin_acc", desc = "Admin account")]
+        #[account(1, writable, name = "config_acc", desc = "Program config")]
+        SetFeeConfig(SetFeeConfigData),
+        #[account(0, signer, name = "admin_acc", desc = "Admin account")]
+        #[account(1, writable, name = "config_acc", desc = "Program config")]
+        UpdateConfig(UpdateConfigData),
+        #[account(0, writable, signer, name = "owner_acc", desc = "Owner account")]
+        #[account(1, writable, name = "swap_acc", desc = "Swap account")]
+        #[account(2, name = "system_program")]
+        MigrateSwap,
+        #[account(0, name = "swap_acc", desc = "Swap account")]
+        #[account(1, name = "vault_base_acc", desc = "Base vault")]
+        #[account(2, name = "vault_quote_acc", desc = "Quote vault")]
+        #[account(3, name = "base_mint_acc", desc = "Base mint")]
+        #[account(4, name = "quote_mint_acc", desc = "Quote mint")]
+        #[account(5, name = "config_acc", desc = "Program config")]
+        #[account(6, optional, name = "referrer_acc", desc = "Referrer registration, to include bonuses")]
+        Quote(QuoteData),
+        #[account(0, signer, name = "user_acc", desc = "Authority over user_quote_acc")]
+        #[account(1, writable, name = "user_base_acc", desc = "Base token account receiving base out (any owner)")]
+        #[account(2, writable, name = "user_quote_acc", desc = "User quote token")]
+        #[account(3, name = "base_mint_acc", desc = "Base mint")]
+        #[account(4, name = "quote_mint_acc", desc = "Quote mint")]
+        #[account(5, name = "token_program")]
+        #[account(6, name = "config_acc", desc = "Program config")]
+        #[account(7, writable, name = "pool_accs", desc = "pool_count entries of swap_acc, vault_base_acc, vault_quote_acc (+ wsol_temp_acc for SOL quotes), then system and ATA programs for SOL quotes, fee_treasury_acc when fee_bps is set, and an optional payer")]
+        BatchSwap(BatchSwapData),
+        #[account(0, signer, name = "first_user_acc", desc = "Authority over the first hop's user_quote_acc")]
+        #[account(1, writable, name = "hop_accs", desc = "first_hop_accounts Swap accounts for the first pool, then Swap accounts for the second pool (first hop user_base_acc = second hop user_quote_acc)")]
+        Route(RouteData),
+        #[account(0, name = "instructions_sysvar", desc = "Instructions sysvar, to read the owner's ed25519 signature check")]
+        #[account(1, writable, name = "rfq_nonces_acc", desc = "RFQ nonce page for the quote's nonce")]
+        #[account(2, writable, signer, name = "user_acc", desc = "Buyer named in the quote, pays for new nonce pages")]
+        #[account(3, writable, name = "swap_acc", desc = "Swap account, followed by the rest of Swap's accounts")]
+        RfqSwap(RfqSwapData),
+        #[account(0, writable, signer, name = "payer_acc", desc = "Pays for the market and its vault")]
+        #[account(1, writable, name = "market_acc", desc = "Market for the (base, quote) pair")]
+        #[account(2, writable, name = "vault_base_acc", desc = "Market base vault (ATA of the market)")]
+        #[account(3, name = "base_mint_acc", desc = "Base mint")]
+        #[account(4, name = "quote_mint_acc", desc = "Quote mint")]
+        #[account(5, name = "config_acc", desc = "Program config")]
+        #[account(6, name = "system_program")]
+        #[account(7, name = "token_program")]
+        #[account(8, name = "ata_program")]
+        CreateMarket,
+        #[account(0, signer, name = "seller_acc", desc = "Seller")]
+        #[account(1, writable, name = "market_acc", desc = "Market")]
+        #[account(2, writable, name = "vault_base_acc", desc = "Market base vault")]
+        #[account(3, writable, name = "seller_base_acc", desc = "Seller base token funding the offer")]
+        #[account(4, name = "seller_quote_acc", desc = "Seller quote token paid on fills")]
+        #[account(5, name = "base_mint_acc", desc = "Base mint")]
+        #[account(6, name = "config_acc", desc = "Program config")]
+        #[account(7, name = "token_program")]
+        #[account(8, optional, writable, name = "evicted_seller_base_acc", desc = "Base token of the highest-priced offer's seller, when the market is full")]
+        PostOffer(PostOfferData),
+        #[account(0, signer, name = "seller_acc", desc = "Seller")]
+        #[account(1, writable, name = "market_acc", desc = "Market")]
+        #[account(2, writable, name = "vault_base_acc", desc = "Market base vault")]
+        #[account(3, writable, name = "seller_base_acc", desc = "Seller base token receiving the unfilled base")]
+        #[account(4, name = "base_mint_acc", desc = "Base mint")]
+        #[account(5, name = "token_program")]
+        CancelOffer(CancelOfferData),
+        #[account(0, signer, name = "user_acc", desc = "User account")]
+        #[account(1, writable, name = "market_acc", desc = "Market")]
+        #[account(2, writable, name = "vault_base_acc", desc = "Market base vault")]
+        #[account(3, writable, name = "user_base_acc", desc = "User base token")]
+        #[account(4, writable, name = "user_quote_acc", desc = "User quote token")]
+        #[account(5, name = "base_mint_acc", desc = "Base mint")]
+        #[account(6, name = "quote_mint_acc", desc = "Quote mint")]
+        #[account(7, name = "token_program")]
+        #[account(8, name = "config_acc", desc = "Program config")]
+        #[account(9, optional, writable, name = "fee_treasury_acc", desc = "Fee treasury quote token (when fee_bps is set)")]
+        #[account(10, writable, name = "seller_quote_accs", desc = "Quote token accounts of the sellers to buy from")]
+        MarketSwap(MarketSwapData),
+        #[account(0, writable, signer, name = "buyer_acc", desc = "Buyer, pays for the order and its vault")]
+        #[account(1, writable, name = "order_acc", desc = "Buy order PDA")]
+        #[account(2, writable, name = "vault_quote_acc", desc = "Order quote vault (ATA of the order)")]
+        #[account(3, writable, name = "buyer_quote_acc", desc = "Buyer quote token funding the order")]
+        #[account(4, name = "buyer_base_acc", desc = "Buyer base token receiving fills")]
+        #[account(5, name = "base_mint_acc", desc = "Base mint")]
+        #[account(6, name = "quote_mint_acc", desc = "Quote mint")]
+        #[account(7, name = "config_acc", desc = "Program config")]
+        #[account(8, name = "system_program")]
+        #[account(9, name = "token_program")]
+        #[account(10, name = "ata_program")]
+        CreateBuyOrder(CreateBuyOrderData),
+        #[account(0, signer, name = "seller_acc", desc = "Seller")]
+        #[account(1, writable, name = "order_acc", desc = "Buy order")]
+        #[account(2, writable, name = "vault_quote_acc", desc = "Order quote vault")]
+        #[account(3, writable, name = "seller_base_acc", desc = "Seller base token")]
+        #[account(4, writable, name = "seller_quote_acc", desc = "Seller quote token")]
+        #[account(5, writable, name = "buyer_base_acc", desc = "Buyer base token named in the order")]
+        #[account(6, name = "base_mint_acc", desc = "Base mint")]
+        #[account(7, name = "quote_mint_acc", desc = "Quote mint")]
+        #[account(8, name = "token_program")]
+        #[account(9, name = "config_acc", desc = "Program config")]
+        #[account(10, optional, writable, name = "fee_treasury_acc", desc = "Fee treasury quote token (when fee_bps is set)")]
+        Fill(FillData),
+        #[account(0, writable, signer, name = "buyer_acc", desc = "Buyer, receives the vault and order rent")]
+        #[account(1, writable, name = "order_acc", desc = "Buy order")]
+        #[account(2, writable, name = "vault_quote_acc", desc = "Order quote vault")]
+        #[account(3, writable, name = "buyer_quote_acc", desc = "Buyer quote token receiving the unfilled quote")]
+        #[account(4, name = "quote_mint_acc", desc = "Quote mint")]
+        #[account(5, name = "token_program")]
+        CancelBuyOrder,
+        #[account(0, signer, name = "owner_acc", desc = "Pool owner")]
+        #[account(1, writable, name = "swap_acc", desc = "Swap account")]
+        SetVolumeTiers(SetVolumeTiersData),
+        #[account(0, signer, name = "owner_acc", desc = "Pool owner")]
+        #[account(1, writable, name = "swap_acc", desc = "Swap account")]
+        SetRoundingPolicy(SetRoundingPolicyData),
+        #[account(0, signer, name = "owner_acc", desc = "Pool owner")]
+        #[account(1, writable, name = "swap_acc", desc = "Swap account")]
+        SetThrottle(SetThrottleData),
     }
 }
@@ -0,0 +1,165 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    ProgramResult,
+};
+use shank::ShankAccount;
+use pinocchio_log::log;
+use pinocchio_token::{
+    instructions::TransferChecked,
+    state::{Mint, TokenAccount},
+};
+use crate::{
+    errors::SwapError,
+    events::{emit, OfferCancelled, OfferPosted},
+    validation::{check_program_owner, check_signer, check_writable},
+    states::{
+        utils::{load_acc, load_acc_mut, load_ix_data, DataLen},
+        Config,
+        Market,
+        Offer,
+    },
+};
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct PostOfferData {
+    /// 1e9-scaled price of 1 base in quote.
+    pub price: u64,
+    /// Base tokens moved from the seller into the market vault.
+    pub base_amount: u64,
+}
+
+impl DataLen for PostOfferData {
+    const LEN: usize = core::mem::size_of::<PostOfferData>();
+}
+
+/// Post a sell offer in the first free slot of the market. When the market is full, an
+/// offer priced below the highest-priced open offer evicts it, refunding its base to
+/// `evicted_seller_base_acc`, a base token account owned by the evicted seller.
+pub fn post_offer(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    log!("Post Offer");
+    let ix_data = unsafe { load_ix_data::<PostOfferData>(data)? };
+    let [
+        seller_acc,
+        market_acc,
+        vault_base_acc,
+        seller_base_acc,
+        seller_quote_acc,
+        base_mint_acc,
+        config_acc,
+        _token_program,
+        rest @ ..
+    ] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysPostOffer.into());
+    };
+
+    check_signer(seller_acc, SwapError::MissingRequiredSignaturePostOffer)?;
+    check_writable(&[market_acc, vault_base_acc, seller_base_acc], SwapError::NotWritablePostOffer)?;
+    check_program_owner(&[market_acc, config_acc], SwapError::WrongProgramOwnerPostOffer)?;
+
+    let config_state = unsafe { load_acc::<Config>(config_acc) }?;
+    Config::validate_pda(config_state.bump_seed, config_acc.key())?;
+    if config_state.paused {
+        return Err(SwapError::ProgramPausedPostOffer.into());
+    }
+    if ix_data.price == 0 || ix_data.base_amount == 0 {
+        return Err(SwapError::InvalidParametersPostOffer.into());
+    }
+
+    let market_state = unsafe { load_acc_mut::<Market>(market_acc) }?;
+    Market::validate_pda(market_state.bump_seed, &market_state.base_mint, &market_state.quote_mint, market_acc.key())?;
+    if market_state.vault_base != *vault_base_acc.key() {
+        return Err(SwapError::WrongVaultBasePostOffer.into());
+    }
+    if market_state.base_mint != *base_mint_acc.key() {
+        return Err(SwapError::WrongMintBasePostOffer.into());
+    }
+    let seller_quote = TokenAccount::from_account_info(seller_quote_acc)?;
+    if *seller_quote.mint() != market_state.quote_mint {
+        return Err(SwapError::WrongMintQuotePostOffer.into());
+    }
+    drop(seller_quote);
+
+    let base_decimals = Mint::from_account_info(base_mint_acc)?.decimals();
+    if ix_data.base_amount < Market::min_offer_base(base_decimals) {
+        return Err(SwapError::OfferTooSmallPostOffer.into());
+    }
+
+    let free_index = market_state.offers.iter().position(|offer| !offer.is_open());
+    let offer_index = match free_index {
+        Some(offer_index) => offer_index,
+        None => {
+            let offer_index = market_state.worst_offer_index().ok_or(SwapError::MarketFullPostOffer)?;
+            let evicted = market_state.offers[offer_index];
+            if ix_data.price >= evicted.price {
+                return Err(SwapError::MarketFullPostOffer.into());
+            }
+            let evicted_seller_base_acc = rest.first().ok_or(SwapError::NotEnoughAccountKeysPostOffer)?;
+            check_writable(&[evicted_seller_base_acc], SwapError::NotWritablePostOffer)?;
+            let evicted_seller_base = TokenAccount::from_account_info(evicted_seller_base_acc)?;
+            if *evicted_seller_base.owner() != evicted.seller || *evicted_seller_base.mint() != market_state.base_mint {
+                return Err(SwapError::WrongEvictedSellerPostOffer.into());
+            }
+            drop(evicted_seller_base);
+
+            let base_mint = market_state.base_mint;
+            let quote_mint = market_state.quote_mint;
+            let pda_bump_bytes = [market_state.bump_seed];
+            let signer_seeds = [
+                Seed::from(Market::SEED),
+                Seed::from(&base_mint),
+                Seed::from(&quote_mint),
+                Seed::from(&pda_bump_bytes[..]),
+            ];
+            let signers = [Signer::from(&signer_seeds[..])];
+
+            log!("Evict offer {} and refund its seller: {}", offer_index, evicted.base_remaining);
+            TransferChecked {
+                from: vault_base_acc,
+                mint: base_mint_acc,
+                to: evicted_seller_base_acc,
+                authority: market_acc,
+                amount: evicted.base_remaining,
+                decimals: base_decimals,
+            }
+            .invoke_signed(&signers)?;
+
+            emit(&OfferCancelled {
+                market: *market_acc.key(),
+                offer_index: offer_index as u8,
+                seller: evicted.seller,
+                base_returned: evicted.base_remaining,
+            });
+            offer_index
+        }
+    };
+
+    log!("Transfer base token from seller to market vault: {}", ix_data.base_amount);
+    TransferChecked {
+        from: seller_base_acc,
+        mint: base_mint_acc,
+        to: vault_base_acc,
+        authority: seller_acc,
+        amount: ix_data.base_amount,
+        decimals: base_decimals,
+    }
+    .invoke()?;
+
+    market_state.offers[offer_index] = Offer {
+        seller: *seller_acc.key(),
+        seller_quote: *seller_quote_acc.key(),
+        price: ix_data.price,
+        base_remaining: ix_data.base_amount,
+    };
+
+    emit(&OfferPosted {
+        market: *market_acc.key(),
+        offer_index: offer_index as u8,
+        seller: *seller_acc.key(),
+        price: ix_data.price,
+        base_amount: ix_data.base_amount,
+    });
+    log!("Offer Posted: {}", offer_index);
+    Ok(())
+}
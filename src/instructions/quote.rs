@@ -0,0 +1,180 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::set_return_data,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use shank::{ShankAccount, ShankType};
+use pinocchio_log::log;
+use pinocchio_token::state::{Mint, TokenAccount};
+use crate::{
+    errors::SwapError,
+    validation::check_program_owner,
+    states::{
+        utils::{load_acc, load_ix_data, to_bytes, DataLen},
+        Config,
+        Referrer,
+        Rounding,
+        SwapState,
+    },
+};
+use super::swap::{compute_quote_units, swap_amounts, Price};
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct QuoteData {
+    /// Quote tokens to pay, or base tokens wanted when `exact_base_out` is 1.
+    pub amount: u64,
+    /// 1 if `amount` is the desired base out rather than `quote_in`.
+    pub exact_base_out: u8,
+}
+
+impl DataLen for QuoteData {
+    const LEN: usize = core::mem::size_of::<QuoteData>();
+}
+
+/// Return data set by the Quote instruction: what `swap()` would move for `quote_in`.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct QuoteResult {
+    /// Quote tokens the buyer pays.
+    pub quote_in: u64,
+    /// Base tokens sent to the buyer.
+    pub base_out: u64,
+    /// Quote tokens paid into the quote vault.
+    pub quote_to_vault: u64,
+    /// Quote tokens paid to the referrer.
+    pub quote_bonus: u64,
+    /// Base tokens paid or locked for the referrer.
+    pub base_bonus: u64,
+    /// Quote tokens paid to the fee treasury.
+    pub protocol_fee: u64,
+    /// 1-based volume tier applied to the pool price (0 = none).
+    pub volume_tier: u8,
+    /// Discount taken off the pool price, in basis points.
+    pub discount_bps: u16,
+}
+
+impl DataLen for QuoteResult {
+    const LEN: usize = core::mem::size_of::<QuoteResult>();
+}
+
+/// Price a swap without moving any tokens. Meant for `simulateTransaction`: the
+/// breakdown is returned as `QuoteResult` return data, computed with the same
+/// rounding as `swap()`.
+pub fn quote(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    log!("Quote");
+    let ix_data = unsafe { load_ix_data::<QuoteData>(data)? };
+    let [
+        swap_acc,
+        vault_base_acc,
+        vault_quote_acc,
+        base_mint_acc,
+        quote_mint_acc,
+        config_acc,
+        referrer_accs @ ..
+    ] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysQuote.into());
+    };
+    check_program_owner(&[swap_acc, config_acc], SwapError::WrongProgramOwnerQuote)?;
+    if ix_data.amount == 0 {
+        return Err(SwapError::InvalidInstructionDataQuoteAmountZero.into());
+    }
+
+    let config_state = unsafe { load_acc::<Config>(config_acc) }?;
+    Config::validate_pda(config_state.bump_seed, config_acc.key())?;
+    if config_state.paused {
+        return Err(SwapError::ProgramPausedQuote.into());
+    }
+
+    let swap_state = unsafe { load_acc::<SwapState>(swap_acc) }?;
+    swap_state.check_pda(swap_acc.key())?;
+    if swap_state.base != *vault_base_acc.key() {
+        return Err(SwapError::WrongVaultBaseQuote.into());
+    }
+    if swap_state.quote != *vault_quote_acc.key() {
+        return Err(SwapError::WrongVaultQuoteQuote.into());
+    }
+
+    let vault_base = TokenAccount::from_account_info(vault_base_acc)?;
+    if vault_base.mint() != base_mint_acc.key() {
+        return Err(SwapError::WrongMintBaseQuote.into());
+    }
+    let vault_base_amount = vault_base.amount();
+    drop(vault_base);
+    let base_decimals = Mint::from_account_info(base_mint_acc)?.decimals();
+    let mut quote_decimals = 9;
+    if !swap_state.quote_sol {
+        let vault_quote = TokenAccount::from_account_info(vault_quote_acc)?;
+        if vault_quote.mint() != quote_mint_acc.key() {
+            return Err(SwapError::WrongMintQuoteQuote.into());
+        }
+        drop(vault_quote);
+        quote_decimals = Mint::from_account_info(quote_mint_acc)?.decimals();
+    }
+
+    // Bonuses only apply when the buyer names a referrer
+    let mut bonus_base_rate = 0;
+    let mut bonus_quote_rate = 0;
+    if let [referrer_acc, ..] = referrer_accs {
+        check_program_owner(&[referrer_acc], SwapError::WrongProgramOwnerQuote)?;
+        let referrer_state = unsafe { load_acc::<Referrer>(referrer_acc) }?;
+        Referrer::validate_pda(referrer_state.bump_seed, swap_acc.key(), &referrer_state.wallet, referrer_acc.key())?;
+        if referrer_state.swap != *swap_acc.key() {
+            return Err(SwapError::WrongSwapReferrer.into());
+        }
+        if !referrer_state.approved {
+            return Err(SwapError::ReferrerNotApprovedQuote.into());
+        }
+        (bonus_base_rate, bonus_quote_rate) = referrer_state.bonus_rates(swap_state);
+    }
+
+    let quote_in = if ix_data.exact_base_out != 0 {
+        // Price at the list price, then take the discounted cost if it still reaches
+        // the same tier; otherwise the list cost reaches the tier and buys a bit more
+        let list_price = Price::new(swap_state.price, swap_state.price_exponent);
+        let list_quote_in = compute_quote_units(ix_data.amount, list_price, base_decimals, quote_decimals)?;
+        let (_, discount_bps) = swap_state.volume_discount(list_quote_in);
+        let price = list_price.discounted(discount_bps)?;
+        let quote_in = compute_quote_units(ix_data.amount, price, base_decimals, quote_decimals)?;
+        if swap_state.volume_discount(quote_in).1 == discount_bps {
+            quote_in
+        } else {
+            list_quote_in
+        }
+    } else {
+        ix_data.amount
+    };
+    if quote_in < swap_state.min_quote_in {
+        return Err(SwapError::QuoteInBelowMinQuote.into());
+    }
+    let (volume_tier, discount_bps) = swap_state.volume_discount(quote_in);
+    let mut result = swap_amounts(
+        Price::new(swap_state.price, swap_state.price_exponent).discounted(discount_bps)?,
+        config_state.fee_bps,
+        bonus_base_rate,
+        bonus_quote_rate,
+        quote_in,
+        base_decimals,
+        quote_decimals,
+        Rounding::try_from(swap_state.rounding)?,
+    )?;
+    result.volume_tier = volume_tier;
+    result.discount_bps = discount_bps;
+
+    // Base held for outstanding bonus locks is not available for sale
+    let base_required = result
+        .base_out
+        .checked_add(result.base_bonus)
+        .ok_or(SwapError::InsufficientVaultBaseQuote)?;
+    if swap_state.available_base(vault_base_amount) < base_required {
+        return Err(SwapError::InsufficientVaultBaseQuote.into());
+    }
+    if result.base_out > swap_state.throttle_allowance(Clock::get()?.slot) {
+        return Err(SwapError::RateLimitedQuote.into());
+    }
+
+    set_return_data(unsafe { to_bytes(&result) });
+    log!("Quote base_out: {}", result.base_out);
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    ProgramResult,
+};
+use pinocchio_log::log;
+use crate::{
+    errors::SwapError,
+    events::{emit, ReferrerRegistered},
+    validation::{check_program_owner, check_signer, check_writable},
+    states::{
+        utils::{create_pda_account, load_acc, DataLen},
+        Referrer,
+        SwapState,
+    },
+};
+
+pub fn register_referrer(accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    log!("Register Referrer");
+    let [wallet_acc, swap_acc, referrer_acc, _system_program] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysRegisterReferrer.into());
+    };
+
+    // Validate referrer wallet is signer
+    check_signer(wallet_acc, SwapError::MissingRequiredSignatureRegisterReferrer)?;
+    check_writable(&[wallet_acc, referrer_acc], SwapError::NotWritableRegisterReferrer)?;
+    check_program_owner(&[swap_acc], SwapError::WrongProgramOwnerRegisterReferrer)?;
+
+    let swap_state = unsafe { load_acc::<SwapState>(swap_acc) }?;
+    swap_state.check_pda(swap_acc.key())?;
+
+    let (pda, bump) = Referrer::find_pda(swap_acc.key(), wallet_acc.key());
+    if pda != *referrer_acc.key() {
+        return Err(SwapError::InvalidPDAValidation.into());
+    }
+    if !referrer_acc.data_is_empty() {
+        return Err(SwapError::AccountAlreadyInitializedRegisterReferrer.into());
+    }
+
+    let pda_bump_bytes = [bump];
+    let signer_seeds = [
+        Seed::from(Referrer::SEED),
+        Seed::from(swap_acc.key()),
+        Seed::from(wallet_acc.key()),
+        Seed::from(&pda_bump_bytes[..]),
+    ];
+    let signers = [Signer::from(&signer_seeds[..])];
+    create_pda_account(wallet_acc, referrer_acc, Referrer::LEN, &signers)?;
+
+    // Pools that require approval start referrers out unapproved
+    let approved = !swap_state.referrer_approval;
+    Referrer::create_referrer(referrer_acc, swap_acc, wallet_acc, bump, approved)?;
+    emit(&ReferrerRegistered {
+        swap: *swap_acc.key(),
+        wallet: *wallet_acc.key(),
+        approved: approved as u8,
+    });
+    log!("Referrer approved: {}", approved);
+    log!("Referrer Registered");
+    Ok(())
+}
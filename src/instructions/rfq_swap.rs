@@ -0,0 +1,127 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::set_return_data,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, instructions::Instructions, Sysvar},
+    ProgramResult,
+};
+use five8_const::decode_32_const;
+use shank::{ShankAccount, ShankType};
+use pinocchio_log::log;
+use crate::{
+    errors::SwapError,
+    states::{
+        utils::{load_acc, load_ix_data, to_bytes, DataLen},
+        RfqNonces,
+        SwapState,
+    },
+};
+use super::swap::{execute_swap, SwapData};
+
+/// Program verifying the owner's signature in the instruction before RfqSwap.
+const ED25519_PROGRAM_ID: Pubkey = decode_32_const("Ed25519SigVerify111111111111111111111111111");
+
+/// Quote signed by the pool owner. The ed25519 message is exactly these bytes.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct RfqQuote {
+    pub swap: Pubkey,
+    /// Only this buyer (`user_acc`) may fill the quote.
+    pub buyer: Pubkey,
+    /// 1e9-scaled price of 1 base in quote, times 10^`price_exponent` of the pool, used
+    /// instead of the pool's price.
+    pub price: u64,
+    /// Most base tokens the buyer may receive.
+    pub max_base_out: u64,
+    /// Unix timestamp after which the quote can no longer be filled.
+    pub expiry: i64,
+    /// Single-use nonce, tracked per pool in `RfqNonces` pages.
+    pub nonce: u64,
+}
+
+impl DataLen for RfqQuote {
+    const LEN: usize = core::mem::size_of::<RfqQuote>();
+}
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct RfqSwapData {
+    /// Amount of quote tokens the user is willing to pay.
+    pub quote_in: u64,
+    pub quote: RfqQuote,
+}
+
+impl DataLen for RfqSwapData {
+    const LEN: usize = core::mem::size_of::<RfqSwapData>();
+}
+
+/// Swap at an owner-signed price. The previous instruction in the transaction must be
+/// an ed25519 signature check of `quote` by the pool owner. The remaining accounts are
+/// the same as Swap's, and `user_acc` pays for new nonce pages.
+pub fn rfq_swap(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    log!("RFQ Swap");
+    let ix_data = unsafe { load_ix_data::<RfqSwapData>(data)? };
+    let [instructions_acc, rfq_nonces_acc, swap_accs @ ..] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysRfqSwap.into());
+    };
+    let [user_acc, swap_acc, ..] = swap_accs else {
+        return Err(SwapError::NotEnoughAccountKeysRfqSwap.into());
+    };
+    let quote = ix_data.quote;
+
+    if quote.swap != *swap_acc.key() {
+        return Err(SwapError::WrongPoolRfqSwap.into());
+    }
+    if quote.buyer != *user_acc.key() {
+        return Err(SwapError::WrongBuyerRfqSwap.into());
+    }
+    if Clock::get()?.unix_timestamp > quote.expiry {
+        return Err(SwapError::ExpiredRfqSwap.into());
+    }
+    let owner = unsafe { load_acc::<SwapState>(swap_acc) }?.owner;
+    verify_owner_signature(instructions_acc, &owner, unsafe { to_bytes(&quote) })?;
+    RfqNonces::use_nonce(rfq_nonces_acc, swap_acc, user_acc, quote.nonce)?;
+
+    let result = execute_swap(swap_accs, SwapData { quote_in: ix_data.quote_in }, Some(quote.price))?;
+    if result.base_out > quote.max_base_out {
+        return Err(SwapError::ExceedsMaxRfqSwap.into());
+    }
+
+    set_return_data(unsafe { to_bytes(&result) });
+    log!("RFQ Swap Completed");
+    Ok(())
+}
+
+/// Check that the instruction before this one is an ed25519 verification of `message`
+/// signed by `owner`, with the key and message inside that instruction's own data.
+fn verify_owner_signature(instructions_acc: &AccountInfo, owner: &Pubkey, message: &[u8]) -> ProgramResult {
+    let invalid = || ProgramError::from(SwapError::InvalidSignatureRfqSwap);
+    let instructions = Instructions::try_from(instructions_acc)?;
+    let ed25519_ix = instructions.get_instruction_relative(-1).map_err(|_| invalid())?;
+    if *ed25519_ix.get_program_id() != ED25519_PROGRAM_ID {
+        return Err(invalid());
+    }
+
+    // [num_signatures: u8, padding: u8, then per signature seven u16 offsets:
+    //  signature, signature ix, public key, public key ix, message, message size, message ix]
+    let ix_data = ed25519_ix.get_instruction_data();
+    if ix_data.len() < 16 || ix_data[0] != 1 {
+        return Err(invalid());
+    }
+    let read_u16 = |at: usize| u16::from_le_bytes([ix_data[at], ix_data[at + 1]]);
+    let public_key_offset = read_u16(6) as usize;
+    let message_offset = read_u16(10) as usize;
+    let message_size = read_u16(12) as usize;
+    // u16::MAX means the data lives in the ed25519 instruction itself
+    if read_u16(4) != u16::MAX || read_u16(8) != u16::MAX || read_u16(14) != u16::MAX {
+        return Err(invalid());
+    }
+
+    let public_key = ix_data.get(public_key_offset..public_key_offset + 32).ok_or_else(invalid)?;
+    let signed_message = ix_data.get(message_offset..message_offset + message_size).ok_or_else(invalid)?;
+    if public_key != owner || signed_message != message {
+        return Err(invalid());
+    }
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::set_return_data,
+    ProgramResult,
+};
+use shank::ShankAccount;
+use pinocchio_log::log;
+use crate::{
+    errors::SwapError,
+    states::utils::{load_ix_data, to_bytes, DataLen},
+};
+use super::swap::{execute_swap, SwapData};
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct RouteData {
+    /// Quote tokens of the first pool the user pays.
+    pub quote_in: u64,
+    /// Least base tokens of the second pool the user accepts.
+    pub min_base_out: u64,
+    /// Number of accounts belonging to the first hop; the rest belong to the second.
+    pub first_hop_accounts: u8,
+}
+
+impl DataLen for RouteData {
+    const LEN: usize = core::mem::size_of::<RouteData>();
+}
+
+/// Buy the second pool's base with the first pool's quote in one instruction: the
+/// base bought from the first pool is spent as the second pool's `quote_in`.
+///
+/// Each hop takes the same accounts as Swap. The first hop's `user_base_acc` must be
+/// the second hop's `user_quote_acc`.
+pub fn route(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    log!("Route");
+    let ix_data = unsafe { load_ix_data::<RouteData>(data)? };
+    let first_hop_len = ix_data.first_hop_accounts as usize;
+    if first_hop_len > accounts.len() {
+        return Err(SwapError::NotEnoughAccountKeysRoute.into());
+    }
+    let (first_hop_accs, second_hop_accs) = accounts.split_at(first_hop_len);
+    let (Some(first_user_base_acc), Some(second_user_quote_acc)) = (first_hop_accs.get(4), second_hop_accs.get(5)) else {
+        return Err(SwapError::NotEnoughAccountKeysRoute.into());
+    };
+    if first_user_base_acc.key() != second_user_quote_acc.key() {
+        return Err(SwapError::MismatchedHopsRoute.into());
+    }
+
+    let first = execute_swap(first_hop_accs, SwapData { quote_in: ix_data.quote_in }, None)?;
+    log!("Route first hop base_out: {}", first.base_out);
+    let second = execute_swap(second_hop_accs, SwapData { quote_in: first.base_out }, None)?;
+    if second.base_out < ix_data.min_base_out {
+        return Err(SwapError::SlippageRoute.into());
+    }
+
+    set_return_data(unsafe { to_bytes(&second) });
+    log!("Route Completed: {}", second.base_out);
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+use shank::ShankAccount;
+use pinocchio_log::log;
+use crate::{
+    errors::SwapError,
+    events::{emit, ConfigUpdated},
+    validation::{check_program_owner, check_signer, check_writable},
+    states::{
+        utils::{load_acc_mut, load_ix_data, DataLen},
+        Config,
+    },
+};
+
+/// Highest protocol fee the admin can set (10% of `quote_in`).
+pub const MAX_FEE_BPS: u16 = 1_000;
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct SetFeeConfigData {
+    /// Wallet receiving protocol fees.
+    pub fee_treasury: Pubkey,
+    /// Protocol fee in basis points of `quote_in`.
+    pub fee_bps: u16,
+}
+
+impl DataLen for SetFeeConfigData {
+    const LEN: usize = core::mem::size_of::<SetFeeConfigData>();
+}
+
+pub fn set_fee_config(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    log!("Set Fee Config");
+    let ix_data = unsafe { load_ix_data::<SetFeeConfigData>(data)? };
+    let [admin_acc, config_acc] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysSetFeeConfig.into());
+    };
+
+    // Validate admin is signer
+    check_signer(admin_acc, SwapError::MissingRequiredSignatureSetFeeConfig)?;
+    check_writable(&[config_acc], SwapError::NotWritableSetFeeConfig)?;
+    check_program_owner(&[config_acc], SwapError::WrongProgramOwnerSetFeeConfig)?;
+
+    let config_state = unsafe { load_acc_mut::<Config>(config_acc) }?;
+    Config::validate_pda(config_state.bump_seed, config_acc.key())?;
+    if config_state.admin != *admin_acc.key() {
+        return Err(SwapError::NotAdminSetFeeConfig.into());
+    }
+    if ix_data.fee_bps > MAX_FEE_BPS {
+        return Err(SwapError::InvalidParametersFeeBps.into());
+    }
+    config_state.fee_treasury = ix_data.fee_treasury;
+    config_state.fee_bps = ix_data.fee_bps;
+
+    emit(&ConfigUpdated::new(config_state));
+    log!("Config fee_bps: {}", ix_data.fee_bps);
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    ProgramResult,
+};
+use shank::ShankAccount;
+use pinocchio_log::log;
+use crate::{
+    errors::SwapError,
+    events::{emit, ReferrerUpdated},
+    validation::{check_program_owner, check_signer, check_writable},
+    states::{
+        utils::{load_acc, load_acc_mut, load_ix_data, DataLen},
+        Referrer,
+        SwapState,
+    },
+};
+use super::create::BONUS_QUOTE_CAP;
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct SetReferrerRatesData {
+    /// Base bonus percentage scaled by 1e9, at most the pool's `bonus_base_max`.
+    pub bonus_base: u64,
+    /// Quote bonus percentage scaled by 1e9, at most the pool's `bonus_quote_max` and below
+    /// `BONUS_QUOTE_CAP`.
+    pub bonus_quote: u64,
+    /// 1 to use these rates, 0 to fall back to the pool's default rates.
+    pub custom_rates: u8,
+}
+
+impl DataLen for SetReferrerRatesData {
+    const LEN: usize = core::mem::size_of::<SetReferrerRatesData>();
+}
+
+pub fn set_referrer_rates(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    log!("Set Referrer Rates");
+    let ix_data = unsafe { load_ix_data::<SetReferrerRatesData>(data)? };
+    let [owner_acc, swap_acc, referrer_acc] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysSetReferrerRates.into());
+    };
+
+    // Validate owner is signer
+    check_signer(owner_acc, SwapError::MissingRequiredSignatureSetReferrerRates)?;
+    check_writable(&[referrer_acc], SwapError::NotWritableSetReferrerRates)?;
+    check_program_owner(&[swap_acc, referrer_acc], SwapError::WrongProgramOwnerSetReferrerRates)?;
+
+    let swap_state = unsafe { load_acc::<SwapState>(swap_acc) }?;
+    swap_state.check_pda(swap_acc.key())?;
+    if swap_state.owner != *owner_acc.key() {
+        return Err(SwapError::NotOwnerSetReferrerRates.into());
+    }
+    if ix_data.bonus_base > swap_state.bonus_base_max
+        || ix_data.bonus_quote > swap_state.bonus_quote_max
+        || ix_data.bonus_quote >= BONUS_QUOTE_CAP
+    {
+        return Err(SwapError::InvalidParametersReferrerRatesMax.into());
+    }
+
+    let referrer_state = unsafe { load_acc_mut::<Referrer>(referrer_acc) }?;
+    Referrer::validate_pda(referrer_state.bump_seed, swap_acc.key(), &referrer_state.wallet, referrer_acc.key())?;
+    if referrer_state.swap != *swap_acc.key() {
+        return Err(SwapError::WrongSwapReferrer.into());
+    }
+    referrer_state.custom_rates = ix_data.custom_rates != 0;
+    referrer_state.bonus_base = ix_data.bonus_base;
+    referrer_state.bonus_quote = ix_data.bonus_quote;
+
+    emit(&ReferrerUpdated::new(referrer_state));
+    log!("Referrer bonus_base: {}%", ix_data.bonus_base / 1_000_000_000);
+    log!("Referrer bonus_quote: {}%", ix_data.bonus_quote / 1_000_000_000);
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    ProgramResult,
+};
+use shank::ShankAccount;
+use pinocchio_log::log;
+use crate::{
+    errors::SwapError,
+    events::{emit, RoundingPolicyUpdated},
+    validation::{check_program_owner, check_signer, check_writable},
+    states::{
+        utils::{load_acc_mut, load_ix_data, DataLen},
+        Rounding,
+        SwapState,
+    },
+};
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct SetRoundingPolicyData {
+    /// `Rounding` of base out: 0 = floor, 1 = ceil, 2 = nearest. Bonuses always round down.
+    pub rounding: u8,
+    /// Smallest `quote_in` a swap may pay (0 = no minimum).
+    pub min_quote_in: u64,
+}
+
+impl DataLen for SetRoundingPolicyData {
+    const LEN: usize = core::mem::size_of::<SetRoundingPolicyData>();
+}
+
+/// Set how the pool rounds swap amounts and the smallest swap it accepts, so dust
+/// trades fail with `QuoteInBelowMinSwap` instead of leaking value to rounding.
+pub fn set_rounding_policy(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    log!("Set Rounding Policy");
+    let ix_data = unsafe { load_ix_data::<SetRoundingPolicyData>(data)? };
+    let [owner_acc, swap_acc] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysSetRoundingPolicy.into());
+    };
+
+    check_signer(owner_acc, SwapError::MissingRequiredSignatureSetRoundingPolicy)?;
+    check_writable(&[swap_acc], SwapError::NotWritableSetRoundingPolicy)?;
+    check_program_owner(&[swap_acc], SwapError::WrongProgramOwnerSetRoundingPolicy)?;
+
+    let swap_state = unsafe { load_acc_mut::<SwapState>(swap_acc) }?;
+    swap_state.check_pda(swap_acc.key())?;
+    if swap_state.owner != *owner_acc.key() {
+        return Err(SwapError::NotOwnerSetRoundingPolicy.into());
+    }
+    Rounding::try_from(ix_data.rounding)?;
+    swap_state.rounding = ix_data.rounding;
+    swap_state.min_quote_in = ix_data.min_quote_in;
+
+    emit(&RoundingPolicyUpdated {
+        swap: *swap_acc.key(),
+        rounding: ix_data.rounding,
+        min_quote_in: ix_data.min_quote_in,
+    });
+    let min_quote_in = ix_data.min_quote_in;
+    log!("SwapState rounding: {}", ix_data.rounding);
+    log!("SwapState min_quote_in: {}", min_quote_in);
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    ProgramResult,
+};
+use shank::ShankAccount;
+use pinocchio_log::log;
+use crate::{
+    errors::SwapError,
+    events::{emit, ThrottleUpdated},
+    validation::{check_program_owner, check_signer, check_writable},
+    states::{
+        utils::{load_acc_mut, load_ix_data, DataLen},
+        SwapState,
+    },
+};
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct SetThrottleData {
+    /// See `SwapState::throttle_window_slots`.
+    pub window_slots: u64,
+    /// Most base tokens sold in a burst, draining by this much per window, must be set when throttled.
+    pub max_base: u64,
+}
+
+impl DataLen for SetThrottleData {
+    const LEN: usize = core::mem::size_of::<SetThrottleData>();
+}
+
+/// Cap how much base the pool sells over a rolling window of slots. Sales count against
+/// the cap and drain linearly, by `max_base` every `window_slots` slots, so swaps past
+/// the cap fail with `RateLimitedSwap` until enough has drained. Clears the current
+/// count.
+pub fn set_throttle(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    log!("Set Throttle");
+    let ix_data = unsafe { load_ix_data::<SetThrottleData>(data)? };
+    let [owner_acc, swap_acc] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysSetThrottle.into());
+    };
+
+    check_signer(owner_acc, SwapError::MissingRequiredSignatureSetThrottle)?;
+    check_writable(&[swap_acc], SwapError::NotWritableSetThrottle)?;
+    check_program_owner(&[swap_acc], SwapError::WrongProgramOwnerSetThrottle)?;
+
+    let swap_state = unsafe { load_acc_mut::<SwapState>(swap_acc) }?;
+    swap_state.check_pda(swap_acc.key())?;
+    if swap_state.owner != *owner_acc.key() {
+        return Err(SwapError::NotOwnerSetThrottle.into());
+    }
+    if ix_data.window_slots != 0 && ix_data.max_base == 0 {
+        return Err(SwapError::InvalidParametersThrottle.into());
+    }
+    swap_state.throttle_window_slots = ix_data.window_slots;
+    swap_state.throttle_max_base = ix_data.max_base;
+    swap_state.throttle_last_slot = 0;
+    swap_state.throttle_base_sold = 0;
+
+    emit(&ThrottleUpdated {
+        swap: *swap_acc.key(),
+        window_slots: ix_data.window_slots,
+        max_base: ix_data.max_base,
+    });
+    let window_slots = ix_data.window_slots;
+    let max_base = ix_data.max_base;
+    log!("SwapState throttle: {} base per {} slots", max_base, window_slots);
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    ProgramResult,
+};
+use shank::ShankAccount;
+use pinocchio_log::log;
+use crate::{
+    errors::SwapError,
+    events::{emit, VolumeTiersUpdated},
+    validation::{check_program_owner, check_signer, check_writable},
+    states::{
+        utils::{load_acc_mut, load_ix_data, DataLen},
+        SwapState,
+        VolumeTier,
+    },
+};
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct SetVolumeTiersData {
+    /// Set tiers first, in strictly ascending `min_quote_in` order; unused tiers zeroed.
+    pub volume_tiers: [VolumeTier; 4],
+}
+
+impl DataLen for SetVolumeTiersData {
+    const LEN: usize = core::mem::size_of::<SetVolumeTiersData>();
+}
+
+/// Replace the pool's volume discount table. Swaps paying at least a tier's
+/// `min_quote_in` buy at the pool price less the deepest reached tier's discount.
+pub fn set_volume_tiers(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    log!("Set Volume Tiers");
+    let ix_data = unsafe { load_ix_data::<SetVolumeTiersData>(data)? };
+    let [owner_acc, swap_acc] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysSetVolumeTiers.into());
+    };
+
+    check_signer(owner_acc, SwapError::MissingRequiredSignatureSetVolumeTiers)?;
+    check_writable(&[swap_acc], SwapError::NotWritableSetVolumeTiers)?;
+    check_program_owner(&[swap_acc], SwapError::WrongProgramOwnerSetVolumeTiers)?;
+
+    let swap_state = unsafe { load_acc_mut::<SwapState>(swap_acc) }?;
+    swap_state.check_pda(swap_acc.key())?;
+    if swap_state.owner != *owner_acc.key() {
+        return Err(SwapError::NotOwnerSetVolumeTiers.into());
+    }
+
+    let tiers = ix_data.volume_tiers;
+    let mut previous: Option<VolumeTier> = None;
+    let mut unset_seen = false;
+    for tier in tiers.iter() {
+        if !tier.is_set() {
+            if tier.min_quote_in != 0 {
+                return Err(SwapError::InvalidParametersVolumeTiers.into());
+            }
+            unset_seen = true;
+            continue;
+        }
+        if unset_seen || tier.discount_bps >= 10_000 {
+            return Err(SwapError::InvalidParametersVolumeTiers.into());
+        }
+        if let Some(previous) = previous {
+            if tier.min_quote_in <= previous.min_quote_in {
+                return Err(SwapError::InvalidParametersVolumeTiers.into());
+            }
+        }
+        previous = Some(*tier);
+    }
+    swap_state.volume_tiers = tiers;
+
+    emit(&VolumeTiersUpdated {
+        swap: *swap_acc.key(),
+        volume_tiers: tiers,
+    });
+    for (i, tier) in tiers.iter().enumerate().filter(|(_, tier)| tier.is_set()) {
+        let min_quote_in = tier.min_quote_in;
+        let discount_bps = tier.discount_bps;
+        log!("Volume tier {}: {} bps off from {}", i + 1, discount_bps, min_quote_in);
+    }
+    Ok(())
+}
@@ -1,7 +1,10 @@
+use core::cmp::Ordering;
 use pinocchio::{
     account_info::AccountInfo,
-    instruction::{Seed, Signer},
+    cpi::set_return_data,
+    instruction::Signer,
     program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
     ProgramResult,
 };
 use pinocchio_log::log;
@@ -10,16 +13,23 @@ use pinocchio_token::{
     state::{TokenAccount, Mint},
 };
 use pinocchio_system::instructions::Transfer;
-use pinocchio_associated_token_account::instructions::{Create, CreateIdempotent};
+use pinocchio_associated_token_account::instructions::CreateIdempotent;
 use five8_const::decode_32_const;
-use shank::ShankAccount;
+use shank::{ShankAccount, ShankType};
 
 use crate::states::{
-    utils::{load_acc_unchecked, DataLen},
+    utils::{load_acc, load_acc_mut, to_bytes, DataLen},
+    BonusLock,
+    Config,
+    Referrer,
+    Rounding,
+    SwapSeeds,
     SwapState,
 };
 use crate::errors::SwapError;
-use core::u64;
+use crate::events::{emit, Swapped};
+use super::quote::QuoteResult;
+use crate::validation::{check_program_owner, check_signer, check_writable};
 
 #[repr(C, packed)]
 #[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
@@ -32,9 +42,113 @@ impl DataLen for SwapData {
     const LEN: usize = core::mem::size_of::<SwapData>();
 }
 
+/// Return data set by `swap()` so CPI callers can see what was delivered.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct SwapResult {
+    /// Base tokens sent to the buyer.
+    pub base_out: u64,
+    /// Quote tokens paid into the quote vault.
+    pub quote_to_vault: u64,
+    /// Quote tokens paid to the referrer.
+    pub quote_bonus: u64,
+    /// Base tokens paid or locked for the referrer.
+    pub base_bonus: u64,
+    /// 1-based volume tier applied to the pool price (0 = none).
+    pub volume_tier: u8,
+    /// Discount taken off the pool price, in basis points.
+    pub discount_bps: u16,
+}
+
+impl DataLen for SwapResult {
+    const LEN: usize = core::mem::size_of::<SwapResult>();
+}
+
+/// Price of 1 base in quote as `mantissa * 10^exponent`, 1e9-scaled like `SwapState.price`,
+/// less `discount_bps`. The discount is applied inside the unit conversions rather than
+/// to the mantissa, so small mantissas keep their precision.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Price {
+    pub(crate) mantissa: u64,
+    pub(crate) exponent: i8,
+    pub(crate) discount_bps: u16,
+}
+
+impl Price {
+    /// A plain 1e9-scaled price.
+    pub(crate) fn scaled(mantissa: u64) -> Self {
+        Self { mantissa, exponent: 0, discount_bps: 0 }
+    }
+
+    /// A pool price `mantissa * 10^exponent`, undiscounted.
+    pub(crate) fn new(mantissa: u64, exponent: i8) -> Self {
+        Self { mantissa, exponent, discount_bps: 0 }
+    }
+
+    /// This price less `discount_bps`, which must leave something to pay.
+    pub(crate) fn discounted(self, discount_bps: u16) -> Result<Self, ProgramError> {
+        if discount_bps >= 10_000 {
+            return Err(SwapError::InvalidParametersVolumeDiscount.into());
+        }
+        Ok(Self { discount_bps, ..self })
+    }
+
+    /// `(keep_bps, 10_000)`, the fraction of the price paid, or `(1, 1)` when undiscounted
+    /// so plain prices keep their full overflow headroom.
+    fn discount_ratio(&self) -> (u128, u128) {
+        if self.discount_bps == 0 {
+            (1, 1)
+        } else {
+            (10_000 - self.discount_bps as u128, 10_000)
+        }
+    }
+
+    /// Order two prices by value, lifting the one with the larger exponent. A value
+    /// too large for u128 is larger than any other.
+    pub(crate) fn compare(&self, other: &Self) -> Ordering {
+        // mantissa * keep_bps / 10_000, cross-multiplied so both sides stay integers
+        let (keep, scale) = self.discount_ratio();
+        let (other_keep, other_scale) = other.discount_ratio();
+        let value = (self.mantissa as u128) * keep * other_scale;
+        let other_value = (other.mantissa as u128) * other_keep * scale;
+        let lift = |value: u128, shift: i32| {
+            10u128
+                .checked_pow(shift as u32)
+                .and_then(|scale| value.checked_mul(scale))
+        };
+        let shift = self.exponent as i32 - other.exponent as i32;
+        if shift >= 0 {
+            lift(value, shift).map_or(Ordering::Greater, |value| value.cmp(&other_value))
+        } else {
+            lift(other_value, -shift).map_or(Ordering::Less, |other_value| value.cmp(&other_value))
+        }
+    }
+}
+
 pub fn swap(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     log!("Begin Swap");
 
+    // log!("Data length: {}, expected: {}", data.len(), SwapData::LEN);
+    if data.len() != SwapData::LEN {
+        return Err(SwapError::InvalidInstructionDataSwapLength.into());
+    }
+    let swap_data = unsafe { *(data.as_ptr() as *const SwapData) };
+    let result = execute_swap(accounts, swap_data, None)?;
+    set_return_data(unsafe { to_bytes(&result) });
+    log!("Swap Completed");
+    // log!("quote_in={} -> base_out={}", swap_data.quote_in, result.base_out);
+    Ok(())
+}
+
+/// Validate and execute one swap against the pool in `accounts`. A given `price` (scaled
+/// by the pool's price exponent) replaces the pool's volume-discounted price. Shared with
+/// Route, which chains two swaps and only sets return data for the final hop, and with
+/// RfqSwap.
+pub(crate) fn execute_swap(
+    accounts: &[AccountInfo],
+    swap_data: SwapData,
+    price: Option<u64>,
+) -> Result<SwapResult, ProgramError> {
     let [
         user_acc,
         swap_acc,
@@ -44,31 +158,47 @@ pub fn swap(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
         user_quote_acc,
         base_mint_acc,
         quote_mint_acc,
-        bonus_base_acc,
-        bonus_quote_acc,
-        wsol_temp_acc,
         token_program_acc,
-        system_program_acc,
-        _ata_program_acc,
+        config_acc,
+        optional_accs @ ..
     ] = accounts else {
         return Err(SwapError::NotEnoughAccountKeysSwap.into());
     };
 
-    if !user_acc.is_signer() {
-        return Err(SwapError::MissingRequiredSignatureSwap.into());
-    }
+    check_signer(user_acc, SwapError::MissingRequiredSignatureSwap)?;
+    check_writable(
+        &[swap_acc, vault_base_acc, vault_quote_acc, user_base_acc, user_quote_acc],
+        SwapError::NotWritableSwap,
+    )?;
+    check_program_owner(&[swap_acc, config_acc], SwapError::WrongProgramOwnerSwap)?;
 
-    // log!("Data length: {}, expected: {}", data.len(), SwapData::LEN);
-    if data.len() != SwapData::LEN {
-        return Err(SwapError::InvalidInstructionDataSwapLength.into());
-    }
-    let swap_data = unsafe { *(data.as_ptr() as *const SwapData) };
     if swap_data.quote_in == 0 {
         return Err(SwapError::InvalidInstructionDataSwapQuoteInZero.into());
     }
 
+    // Load program config
+    let config_state = unsafe { load_acc::<Config>(config_acc) }?;
+    Config::validate_pda(config_state.bump_seed, config_acc.key())?;
+    if config_state.paused {
+        return Err(SwapError::ProgramPausedSwap.into());
+    }
+
     // Load swap state
-    let swap_state = unsafe { load_acc_unchecked::<SwapState>(swap_acc.borrow_data_unchecked()) }?;
+    let swap_state = unsafe { load_acc_mut::<SwapState>(swap_acc) }?;
+    swap_state.check_pda(swap_acc.key())?;
+    if swap_data.quote_in < swap_state.min_quote_in {
+        return Err(SwapError::QuoteInBelowMinSwap.into());
+    }
+
+    let optional_accs = OptionalSwapAccounts::parse(optional_accs, swap_state, config_state)?;
+    // `user_acc` only has to authorize the quote transfer, so it may be a PDA signing
+    // through CPI. Rent and lamport moves are funded by a separate payer when given.
+    let payer_acc = optional_accs.payer.unwrap_or(user_acc);
+    check_signer(payer_acc, SwapError::MissingRequiredSignatureSwapPayer)?;
+    check_writable(&[payer_acc], SwapError::NotWritableSwap)?;
+    // Pools without bonuses fall back to the buyer's own accounts, which never earn one
+    let [bonus_base_acc, bonus_quote_acc, referrer_acc] =
+        optional_accs.bonus.unwrap_or([user_base_acc, user_quote_acc, swap_acc]);
 
     // Decode all accounts once and extract needed values
     let vault_base = TokenAccount::from_account_info(vault_base_acc)?;
@@ -79,7 +209,9 @@ pub fn swap(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     // Extract all needed values immediately
     let vault_base_owner = *vault_base.owner();
     let vault_base_mint = *vault_base.mint();
+    let vault_base_amount = vault_base.amount();
     let user_base_mint = *user_base.mint();
+    let user_base_owner = *user_base.owner();
     let user_quote_mint = *user_quote.mint();
     let base_decimals = base_mint.decimals();
     let mut quote_decimals = 9;
@@ -130,143 +262,145 @@ pub fn swap(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     drop(user_quote);
     drop(base_mint);
 
-    // Compute base_out (base smallest units) from quote_in (quote smallest units) and 1e9-scaled price.
-    let quote_in_units: u128 = swap_data.quote_in as u128;
-    let price_scaled: u128 = swap_state.price as u128; // 1e9-scaled price of 1 base in quote
-    let base_out: u64 = compute_base_units(quote_in_units, price_scaled, base_decimals, quote_decimals)?;
-
-    if base_out == 0 {
-        return Err(SwapError::InvalidParametersBaseUnitsResultZero.into());
-    }
-    
-    let mut quote_in_bonus = 0;
-    // SPL token or WSOL/SOL
-    if swap_state.bonus_quote != 0 && *bonus_quote_acc.key() != *user_quote_acc.key() {
-        quote_in_bonus = calculate_quote_bonus(swap_state.bonus_quote, swap_data.quote_in)?;
-        // log!("Quote bonus: {}", quote_in_bonus);
-    }
-
-    let quote_in_vault: u64 = swap_data.quote_in.checked_sub(quote_in_bonus).ok_or(SwapError::InvalidParametersQuoteInVaultSubtraction)?;
-    
-    // Transfer base from vault_base to user using PDA signer
-    let uuid_binding = swap_state.uuid.to_le_bytes();
-    let pda_bump_bytes = [swap_state.bump_seed];
-    let signer_seeds = [
-        Seed::from(&uuid_binding),
-        Seed::from(&pda_bump_bytes[..]),
-    ];
-    let signers = [Signer::from(&signer_seeds[..])];
-    
-    // Transfer quote from user to vault_quot
-    if swap_state.quote_sol {
-        // Idempotent create WSOL ATA
-        // log!("Create temp WSOL ATA");
-        CreateIdempotent {
-            funding_account: user_acc,
-            account: wsol_temp_acc,
-            wallet: swap_acc,
-            mint: quote_mint_acc,
-            system_program: system_program_acc,
-            token_program: token_program_acc,
+    // Bonuses are only paid to a registered (and, if required, approved) referrer
+    let wants_quote_bonus = swap_state.bonus_quote_max != 0 && *bonus_quote_acc.key() != *user_quote_acc.key();
+    let wants_base_bonus = swap_state.bonus_base_max != 0 && *bonus_base_acc.key() != *user_base_acc.key();
+    let mut referrer_wallet = [0u8; 32];
+    let mut bonus_base_rate = 0;
+    let mut bonus_quote_rate = 0;
+    if wants_quote_bonus || wants_base_bonus {
+        let referrer_state = unsafe { load_acc::<Referrer>(referrer_acc) }?;
+        Referrer::validate_pda(referrer_state.bump_seed, swap_acc.key(), &referrer_state.wallet, referrer_acc.key())?;
+        if referrer_state.swap != *swap_acc.key() {
+            return Err(SwapError::WrongSwapReferrer.into());
         }
-        .invoke_signed(&signers)?;
-
-        // First tranfer from user
-        TransferChecked {
-            from: user_quote_acc,
-            mint: quote_mint_acc, // WSOL
-            to: wsol_temp_acc,
-            authority: user_acc,
-            amount: quote_in_vault,
-            decimals: quote_decimals,
+        if !referrer_state.approved {
+            return Err(SwapError::ReferrerNotApprovedSwap.into());
         }
-        .invoke()?;
-
-        CloseAccount {
-            account: wsol_temp_acc,
-            destination: user_acc,
-            authority: swap_acc,
+        // The swapper may not refer themselves through the payer or the base recipient either
+        let wallet = referrer_state.wallet;
+        if wallet == *user_acc.key() || wallet == *payer_acc.key() || wallet == user_base_owner {
+            return Err(SwapError::ReferrerIsUserSwap.into());
         }
-        .invoke_signed(&signers)?;
-        
-        // Transfer SOL from user acc to swap_state.quote
-        log!("Transfer SOL from user to vault: {}", quote_in_vault);
-        Transfer {
-            from: user_acc,
-            to: vault_quote_acc,
-            lamports: quote_in_vault,
-        }
-        .invoke()?;
-    } else {
-        log!("Transfer quote token from user to vault: {}", quote_in_vault);
-        TransferChecked {
-            from: user_quote_acc,
-            mint: quote_mint_acc,
-            to: vault_quote_acc,
-            authority: user_acc,
-            amount: quote_in_vault,
-            decimals: quote_decimals,
+        referrer_wallet = wallet;
+        (bonus_base_rate, bonus_quote_rate) = referrer_state.bonus_rates(swap_state);
+    }
+    let pay_quote_bonus = wants_quote_bonus && bonus_quote_rate != 0;
+    let pay_base_bonus = wants_base_bonus && bonus_base_rate != 0;
+
+    let (price, volume_tier, discount_bps) = match price {
+        Some(price) => (Price::new(price, swap_state.price_exponent), 0, 0),
+        None => {
+            let (volume_tier, discount_bps) = swap_state.volume_discount(swap_data.quote_in);
+            let price = Price::new(swap_state.price, swap_state.price_exponent).discounted(discount_bps)?;
+            (price, volume_tier, discount_bps)
         }
-        .invoke()?;
+    };
+    if volume_tier != 0 {
+        log!("Volume tier {}: {} bps off", volume_tier, discount_bps);
     }
+    let amounts = swap_amounts(
+        price,
+        config_state.fee_bps,
+        if pay_base_bonus { bonus_base_rate } else { 0 },
+        if pay_quote_bonus { bonus_quote_rate } else { 0 },
+        swap_data.quote_in,
+        base_decimals,
+        quote_decimals,
+        Rounding::try_from(swap_state.rounding)?,
+    )?;
+    let base_out = amounts.base_out;
+    let quote_in_vault = amounts.quote_to_vault;
+    let quote_in_bonus = amounts.quote_bonus;
+    let base_bonus = amounts.base_bonus;
+    let protocol_fee = amounts.protocol_fee;
 
     // SPL token or WSOL/SOL
-    if quote_in_bonus > 0 {
+    if pay_quote_bonus {
         if swap_state.quote_sol {
-            // Transfer to swap_state.quote as a regular lamports balance
-            // Receive as wrapped SOL to an WSOL ATA owned by the swap account
-
-            Create {
-                funding_account: user_acc,
-                account: wsol_temp_acc,
-                wallet: swap_acc,
-                mint: quote_mint_acc,
-                system_program: system_program_acc,
-                token_program: token_program_acc,
+            if *bonus_quote_acc.key() != referrer_wallet {
+                return Err(SwapError::WrongOwnerQuoteSwapBonus.into());
             }
-            .invoke_signed(&signers)?;
-
-            // Transfer WSOL
-            TransferChecked {
-                from: user_quote_acc,
-                mint: quote_mint_acc,
-                to: wsol_temp_acc,
-                authority: user_acc,
-                amount: quote_in_bonus,
-                decimals: quote_decimals,
-            }
-            .invoke()?;
-
-            // Close WSOL ATA
-            CloseAccount {
-                account: wsol_temp_acc,
-                destination: user_acc,
-                authority: swap_acc,
+        } else {
+            let quote_ata_bonus = TokenAccount::from_account_info(bonus_quote_acc)?;
+            if *quote_ata_bonus.owner() != referrer_wallet {
+                return Err(SwapError::WrongOwnerQuoteSwapBonus.into());
             }
-            .invoke_signed(&signers)?; 
+        }
+    }
 
-            log!("Transfer SOL from user to bonus: {}", quote_in_bonus);
-            Transfer {
-                from: user_acc,
-                to: bonus_quote_acc,
-                lamports: quote_in_bonus,
+    // Protocol fee goes to the treasury recorded in the config
+    if protocol_fee > 0 {
+        let fee_treasury_acc = optional_accs.fee_treasury.ok_or(SwapError::NotEnoughAccountKeysSwap)?;
+        if swap_state.quote_sol {
+            if *fee_treasury_acc.key() != config_state.fee_treasury {
+                return Err(SwapError::WrongTreasurySwap.into());
             }
-            .invoke()?;
         } else {
-            //let _quote_ata_bonus = TokenAccount::from_account_info(bonus_quote_acc)?;
-            log!("Transfer quote token from user to bonus: {}", quote_in_bonus);
-            TransferChecked {
-                from: user_quote_acc,
-                mint: quote_mint_acc,
-                to: bonus_quote_acc,
-                authority: user_acc,
-                amount: quote_in_bonus,
-                decimals: quote_decimals,
+            let treasury_ata = TokenAccount::from_account_info(fee_treasury_acc)?;
+            if *treasury_ata.owner() != config_state.fee_treasury {
+                return Err(SwapError::WrongTreasurySwap.into());
             }
-            .invoke()?;
         }
     }
 
+    // Always SPL token
+    if pay_base_bonus {
+        let base_ata_bonus = TokenAccount::from_account_info(bonus_base_acc)?;
+        if *base_ata_bonus.mint() != *base_mint_acc.key() {
+            return Err(SwapError::WrongMintBaseSwapBonus.into());
+        }
+        if *base_ata_bonus.owner() != referrer_wallet {
+            return Err(SwapError::WrongOwnerBaseSwapBonus.into());
+        }
+        // Drop the immutable borrow on bonus_base_acc before doing a transfer that
+        // will require a (mutable) borrow of the same account.
+        drop(base_ata_bonus);
+    }
+
+    // Base held for outstanding bonus locks is not available for sale
+    let base_required = base_out.checked_add(base_bonus).ok_or(SwapError::InsufficientVaultBaseSwap)?;
+    if swap_state.available_base(vault_base_amount) < base_required {
+        return Err(SwapError::InsufficientVaultBaseSwap.into());
+    }
+    // Throttled pools only sell so much base per window of slots; clients retry once it drains
+    let slot = Clock::get()?.slot;
+    if base_out > swap_state.throttle_allowance(slot) {
+        return Err(SwapError::RateLimitedSwap.into());
+    }
+    
+    // Transfer base from vault_base to the recipient using PDA signer
+    let swap_seeds = SwapSeeds::new(swap_state);
+    let (signer_seeds, seeds_len) = swap_seeds.seeds();
+    let signers = [Signer::from(&signer_seeds[..seeds_len])];
+    
+    let quote_transfer = QuoteTransfer {
+        user_acc,
+        payer_acc,
+        user_quote_acc,
+        quote_mint_acc,
+        swap_acc,
+        token_program_acc,
+        wsol: optional_accs.wsol,
+        decimals: quote_decimals,
+    };
+
+    // Transfer quote from user to vault_quote
+    log!("Transfer quote from user to vault: {}", quote_in_vault);
+    quote_transfer.invoke(vault_quote_acc, quote_in_vault, &signers)?;
+
+    // SPL token or WSOL/SOL
+    if quote_in_bonus > 0 {
+        log!("Transfer quote from user to bonus: {}", quote_in_bonus);
+        quote_transfer.invoke(bonus_quote_acc, quote_in_bonus, &signers)?;
+    }
+
+    if protocol_fee > 0 {
+        let fee_treasury_acc = optional_accs.fee_treasury.ok_or(SwapError::NotEnoughAccountKeysSwap)?;
+        log!("Transfer quote from user to treasury: {}", protocol_fee);
+        quote_transfer.invoke(fee_treasury_acc, protocol_fee, &signers)?;
+    }
+
     // Base tokens
 
     log!("Transfer base token from vault to user: {}", base_out);
@@ -280,31 +414,292 @@ pub fn swap(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     }
     .invoke_signed(&signers)?;
 
-    // Always SPL token
-    if swap_state.bonus_base != 0 && *bonus_base_acc.key() != *user_base_acc.key() {
-        let base_ata_bonus = TokenAccount::from_account_info(bonus_base_acc)?;
-        if *base_ata_bonus.mint() != *base_mint_acc.key() {
-            return Err(SwapError::WrongMintBaseSwapBonus.into());
+    if base_bonus > 0 {
+        if swap_state.bonus_lock_period > 0 {
+            // Keep the bonus in the vault and accrue it to the recipient's lock
+            let now = Clock::get()?.unix_timestamp;
+            log!("Lock base token bonus for {}s: {}", swap_state.bonus_lock_period, base_bonus);
+            let bonus_lock_acc = optional_accs.bonus_lock.ok_or(SwapError::NotEnoughAccountKeysSwap)?;
+            BonusLock::accrue(
+                bonus_lock_acc,
+                swap_acc,
+                bonus_base_acc,
+                payer_acc,
+                base_bonus,
+                now,
+                swap_state.bonus_lock_period,
+            )?;
+            swap_state.bonus_locked = swap_state
+                .bonus_locked
+                .checked_add(base_bonus)
+                .ok_or(SwapError::InvalidParametersBonusLockedOverflow)?;
+        } else {
+            log!("Transfer base token from vault to bonus: {}", base_bonus);
+            TransferChecked {
+                from: vault_base_acc,
+                mint: base_mint_acc,
+                to: bonus_base_acc,
+                authority: swap_acc,
+                amount: base_bonus,
+                decimals: base_decimals,
+            }
+            .invoke_signed(&signers)?;
         }
-        let bonus_base_amount = calculate_base_bonus(swap_state.bonus_base as u128, base_out)?;
-        // Drop the immutable borrow on bonus_base_acc before doing a transfer that
-        // will require a (mutable) borrow of the same account.
-        drop(base_ata_bonus);
-        log!("Transfer base token from vault to bonus: {}", bonus_base_amount);
+    }
+
+    swap_state.record_throttle(base_out, slot)?;
+    swap_state.record_swap(quote_in_vault, base_out, base_bonus, quote_in_bonus, slot)?;
+
+    emit(&Swapped {
+        swap: *swap_acc.key(),
+        buyer: *user_acc.key(),
+        quote_in: swap_data.quote_in,
+        base_out,
+        quote_to_vault: quote_in_vault,
+        quote_bonus: quote_in_bonus,
+        base_bonus,
+        protocol_fee,
+        price: price.mantissa,
+        price_exponent: swap_state.price_exponent,
+        base_bonus_locked: (base_bonus > 0 && swap_state.bonus_lock_period > 0) as u8,
+        volume_tier,
+        discount_bps,
+    });
+    Ok(SwapResult {
+        base_out,
+        quote_to_vault: quote_in_vault,
+        quote_bonus: quote_in_bonus,
+        base_bonus,
+        volume_tier,
+        discount_bps,
+    })
+}
+
+/// Accounts following `config_acc`, each group present only when the pool or config
+/// needs it, in this order:
+/// - SOL-quoted pools: `wsol_temp_acc`, system program, associated token program
+/// - pools with bonuses (`bonus_base_max` or `bonus_quote_max` set): `bonus_base_acc`,
+///   `bonus_quote_acc`, `referrer_acc`
+/// - pools locking base bonuses: `bonus_lock_acc`, system program
+/// - protocol fee enabled: `fee_treasury_acc`
+/// - optionally, a separate `payer_acc`
+struct OptionalSwapAccounts<'a> {
+    wsol: Option<[&'a AccountInfo; 2]>,
+    bonus: Option<[&'a AccountInfo; 3]>,
+    bonus_lock: Option<&'a AccountInfo>,
+    fee_treasury: Option<&'a AccountInfo>,
+    payer: Option<&'a AccountInfo>,
+}
+
+impl<'a> OptionalSwapAccounts<'a> {
+    fn parse(accounts: &'a [AccountInfo], swap_state: &SwapState, config_state: &Config) -> Result<Self, ProgramError> {
+        let mut iter = accounts.iter();
+        let mut next = || iter.next().ok_or(ProgramError::from(SwapError::NotEnoughAccountKeysSwap));
+
+        let mut wsol = None;
+        if swap_state.quote_sol {
+            let wsol_temp_acc = next()?;
+            let system_program_acc = next()?;
+            let _ata_program_acc = next()?;
+            wsol = Some([wsol_temp_acc, system_program_acc]);
+        }
+        let mut bonus = None;
+        if swap_state.bonus_base_max != 0 || swap_state.bonus_quote_max != 0 {
+            bonus = Some([next()?, next()?, next()?]);
+        }
+        let mut bonus_lock = None;
+        if swap_state.bonus_base_max != 0 && swap_state.bonus_lock_period > 0 {
+            bonus_lock = Some(next()?);
+            let _system_program_acc = next()?;
+        }
+        let mut fee_treasury = None;
+        if config_state.fee_bps != 0 {
+            fee_treasury = Some(next()?);
+        }
+        let payer = next().ok();
+
+        Ok(Self {
+            wsol,
+            bonus,
+            bonus_lock,
+            fee_treasury,
+            payer,
+        })
+    }
+}
+
+/// Quote transfer from the user, either as an SPL token transfer or, for SOL-quoted
+/// pools, by unwrapping the user's WSOL through `wsol_temp_acc` and sending lamports.
+/// The unwrapped lamports pass through `payer_acc`, which must be a system account.
+pub(crate) struct QuoteTransfer<'a> {
+    pub(crate) user_acc: &'a AccountInfo,
+    pub(crate) payer_acc: &'a AccountInfo,
+    pub(crate) user_quote_acc: &'a AccountInfo,
+    pub(crate) quote_mint_acc: &'a AccountInfo,
+    pub(crate) swap_acc: &'a AccountInfo,
+    pub(crate) token_program_acc: &'a AccountInfo,
+    /// `wsol_temp_acc` and the system program, present for SOL-quoted pools.
+    pub(crate) wsol: Option<[&'a AccountInfo; 2]>,
+    pub(crate) decimals: u8,
+}
+
+impl QuoteTransfer<'_> {
+    /// `signers` must be the swap PDA seeds, which own the temporary WSOL account.
+    pub(crate) fn invoke(&self, to: &AccountInfo, amount: u64, signers: &[Signer]) -> ProgramResult {
+        let Some([wsol_temp_acc, system_program_acc]) = self.wsol else {
+            return TransferChecked {
+                from: self.user_quote_acc,
+                mint: self.quote_mint_acc,
+                to,
+                authority: self.user_acc,
+                amount,
+                decimals: self.decimals,
+            }
+            .invoke();
+        };
+
+        // Idempotent create WSOL ATA
+        CreateIdempotent {
+            funding_account: self.payer_acc,
+            account: wsol_temp_acc,
+            wallet: self.swap_acc,
+            mint: self.quote_mint_acc,
+            system_program: system_program_acc,
+            token_program: self.token_program_acc,
+        }
+        .invoke_signed(signers)?;
+
+        // Move WSOL from the user into the temp account
         TransferChecked {
-            from: vault_base_acc,
-            mint: base_mint_acc,
-            to: bonus_base_acc,
-            authority: swap_acc,
-            amount: bonus_base_amount,
-            decimals: base_decimals,
+            from: self.user_quote_acc,
+            mint: self.quote_mint_acc,
+            to: wsol_temp_acc,
+            authority: self.user_acc,
+            amount,
+            decimals: self.decimals,
         }
-        .invoke_signed(&signers)?;
+        .invoke()?;
+
+        // Closing the temp account unwraps it back to the user as lamports
+        CloseAccount {
+            account: wsol_temp_acc,
+            destination: self.payer_acc,
+            authority: self.swap_acc,
+        }
+        .invoke_signed(signers)?;
+
+        Transfer {
+            from: self.payer_acc,
+            to,
+            lamports: amount,
+        }
+        .invoke()
     }
+}
 
-    log!("Swap Completed");
-    // log!("quote_in={} -> base_out={} price={}", swap_data.quote_in, base_out, swap_state.price);
-    Ok(())
+/// Amounts moved by a swap of `quote_in`, with the bonus rates set to 0 when no bonus
+/// is paid. Shared with the Quote instruction so simulated quotes match swaps exactly.
+/// `rounding` applies to base out only. Bonuses and the protocol fee always round down,
+/// in the pool's favour.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn swap_amounts(
+    price: Price,
+    fee_bps: u16,
+    bonus_base_rate: u64,
+    bonus_quote_rate: u64,
+    quote_in: u64,
+    base_decimals: u8,
+    quote_decimals: u8,
+    rounding: Rounding,
+) -> Result<QuoteResult, ProgramError> {
+    // Compute base_out (base smallest units) from quote_in (quote smallest units) and the pool price.
+    let base_out = compute_base_units(quote_in as u128, price, base_decimals, quote_decimals, rounding)?;
+    if base_out == 0 {
+        return Err(SwapError::InvalidParametersBaseUnitsResultZero.into());
+    }
+
+    let quote_bonus = calculate_quote_bonus(bonus_quote_rate, quote_in)?;
+    let protocol_fee = calculate_protocol_fee(fee_bps, quote_in)?;
+    let quote_to_vault = quote_in
+        .checked_sub(quote_bonus)
+        .and_then(|v| v.checked_sub(protocol_fee))
+        .ok_or(SwapError::InvalidParametersQuoteInVaultSubtraction)?;
+    let base_bonus = calculate_base_bonus(bonus_base_rate as u128, base_out)?;
+
+    Ok(QuoteResult {
+        quote_in,
+        base_out,
+        quote_to_vault,
+        quote_bonus,
+        base_bonus,
+        protocol_fee,
+        volume_tier: 0,
+        discount_bps: 0,
+    })
+}
+
+/// Smallest `quote_in` for which `compute_base_units` returns at least `base_out` when
+/// rounding down, so enough under any `Rounding`
+///
+/// # Formula
+/// quote_units = ceil(base_out * mantissa * keep_bps / 10_000 * 10^(quote_decimals + exponent - base_decimals - 9))
+#[inline(always)]
+pub(crate) fn compute_quote_units(
+    base_out: u64,
+    price: Price,
+    base_decimals: u8,
+    quote_decimals: u8,
+) -> Result<u64, ProgramError> {
+    let net = quote_decimals as i32 + price.exponent as i32 - base_decimals as i32 - 9;
+    let (keep, scale) = price.discount_ratio();
+    let value: u128 = (base_out as u128)
+        .checked_mul(price.mantissa as u128)
+        .and_then(|value| value.checked_mul(keep))
+        .ok_or(SwapError::InvalidParametersQuoteUnitsOverflow)?;
+    let units: u128 = if net >= 0 {
+        value
+            .checked_mul(pow10(net, SwapError::InvalidParametersQuoteScaleOverflow)?)
+            .ok_or(SwapError::InvalidParametersQuoteUnitsOverflow)?
+            .div_ceil(scale)
+    } else {
+        let den = pow10(-net, SwapError::InvalidParametersBaseScaleOverflow)?
+            .checked_mul(scale)
+            .ok_or(SwapError::InvalidParametersBaseScaleOverflow)?;
+        value.div_ceil(den)
+    };
+    if units == 0 || units > (u64::MAX as u128) {
+        return Err(SwapError::InvalidParametersQuoteUnitsOverflow.into());
+    }
+    Ok(units as u64)
+}
+
+/// Calculate the protocol fee owed to the treasury
+///
+/// # Arguments
+/// * `fee_bps` - Fee in basis points (10_000 = 100%)
+/// * `quote_in` - Quote tokens the user is paying
+///
+/// # Formula
+/// fee_amount = (quote_in * fee_bps) / 10_000
+#[inline(always)]
+fn calculate_protocol_fee(
+    fee_bps: u16,
+    quote_in: u64,
+) -> Result<u64, ProgramError> {
+    if fee_bps == 0 {
+        return Ok(0);
+    }
+
+    let fee_amount = (quote_in as u128)
+        .checked_mul(fee_bps as u128)
+        .ok_or(SwapError::InvalidParametersProtocolFeeCalculation)?
+        / 10_000u128;
+
+    if fee_amount > (u64::MAX as u128) {
+        return Err(SwapError::InvalidParametersProtocolFeeCalculation.into());
+    }
+
+    Ok(fee_amount as u64)
 }
 
 /// Calculate base token bonus based on percentage
@@ -399,41 +794,48 @@ fn calculate_quote_bonus(
 
 
 #[inline(always)]
-fn compute_base_units(
+pub(crate) fn compute_base_units(
     quote_units: u128,
-    price_scaled: u128,
+    price: Price,
     base_decimals: u8,
     quote_decimals: u8,
+    rounding: Rounding,
 ) -> Result<u64, ProgramError> {
-    // price_scaled: 1e9-scaled price of 1 base in quote (post-decimals)
-    // Compute base_units = (quote_units * 10^base_decimals * 1e9) / (price_scaled * 10^quote_decimals)
-    if price_scaled == 0 {
+    // price: 1e9-scaled price of 1 base in quote (post-decimals), times 10^exponent
+    // Compute base_units = (quote_units * 10^base_decimals * 1e9) / (mantissa * 10^exponent * 10^quote_decimals)
+    // with the powers of ten cancelled into one, so only one side of the division grows.
+    // A volume discount multiplies the numerator by 10_000 and the denominator by keep_bps.
+    if price.mantissa == 0 {
         return Err(SwapError::InvalidParametersPriceScaledZero.into());
     }
-    let b: u128 = 1_000_000_000u128;
-    let base_scale: u128 = 10u128
-        .checked_pow(base_decimals as u32)
-        .ok_or(SwapError::InvalidParametersBaseScaleOverflow)?;
-    let quote_scale: u128 = 10u128
-        .checked_pow(quote_decimals as u32)
-        .ok_or(SwapError::InvalidParametersQuoteScaleOverflow)?;
-
-    let num: u128 = quote_units
-        .checked_mul(base_scale)
-        .and_then(|v| v.checked_mul(b))
-        .ok_or(SwapError::InvalidParametersBaseUnitsCalculation)?;
-    let den: u128 = price_scaled
-        .checked_mul(quote_scale)
-        .ok_or(SwapError::InvalidParametersBaseUnitsOverflow)?;
-    let units: u128 = num
-        .checked_div(den)
-        .ok_or(SwapError::InvalidParametersBaseUnitsResult)?;
+    let net = base_decimals as i32 + 9 - quote_decimals as i32 - price.exponent as i32;
+    let (keep, scale) = price.discount_ratio();
+    let (num, den): (u128, u128) = if net >= 0 {
+        let num = quote_units
+            .checked_mul(pow10(net, SwapError::InvalidParametersBaseScaleOverflow)?)
+            .ok_or(SwapError::InvalidParametersBaseUnitsCalculation)?;
+        (num, price.mantissa as u128)
+    } else {
+        let den = (price.mantissa as u128)
+            .checked_mul(pow10(-net, SwapError::InvalidParametersQuoteScaleOverflow)?)
+            .ok_or(SwapError::InvalidParametersBaseUnitsOverflow)?;
+        (quote_units, den)
+    };
+    let num = num.checked_mul(scale).ok_or(SwapError::InvalidParametersBaseUnitsCalculation)?;
+    let den = den.checked_mul(keep).ok_or(SwapError::InvalidParametersBaseUnitsOverflow)?;
+    let units: u128 = rounding.div(num, den);
     if units == 0 || units > (u64::MAX as u128) {
         return Err(SwapError::InvalidParametersBaseUnitsResult.into());
     }
     Ok(units as u64)
 }
 
+/// `10^exp` for a non-negative `exp`, failing with `err` when it does not fit in u128.
+#[inline(always)]
+fn pow10(exp: i32, err: SwapError) -> Result<u128, ProgramError> {
+    10u128.checked_pow(exp as u32).ok_or(err.into())
+}
+
 // ---
 // Price and formula explanation
 //
@@ -464,7 +866,14 @@ fn compute_base_units(
 // which is exactly what the function computes with checked u128 arithmetic:
 //   numerator   = quote_units * 10^base_decimals * 1e9
 //   denominator = price_scaled * 10^quote_decimals
-//   base_units  = numerator / denominator
+//   base_units  = numerator / denominator, rounded per the pool's `Rounding`
+//
+// Price exponent
+// - A pool may store its price as price_scaled = mantissa * 10^exponent, so prices far
+//   below 1e-9 or above u64::MAX / 1e9 stay representable. The powers of ten
+//   10^base_decimals, 1e9, 10^quote_decimals and 10^exponent cancel into a single
+//   10^net, applied to whichever side of the division it grows, which keeps the
+//   intermediate products small. Exponent 0 is the plain 1e9-scaled price.
 //
 // Properties
 // - Precision: Because we multiply by both 10^base_decimals and 1e9 before dividing, we
@@ -475,3 +884,144 @@ fn compute_base_units(
 // ---
 
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE: u64 = 1_000_000_000;
+
+    fn err(e: SwapError) -> Result<u64, ProgramError> {
+        Err(e.into())
+    }
+
+    #[test]
+    fn base_units_non_negative_net_exponent() {
+        // net = 6 + 9 - 6 - 0 = 9
+        assert_eq!(compute_base_units(1_000_000, Price::scaled(ONE), 6, 6, Rounding::Floor), Ok(1_000_000));
+        assert_eq!(compute_base_units(1_000_000, Price::scaled(2 * ONE), 6, 6, Rounding::Floor), Ok(500_000));
+    }
+
+    #[test]
+    fn base_units_negative_net_exponent() {
+        // net = 0 + 9 - 18 - 0 = -9
+        assert_eq!(
+            compute_base_units(4_000_000_000_000_000_000, Price::scaled(2 * ONE), 0, 18, Rounding::Floor),
+            Ok(2)
+        );
+    }
+
+    #[test]
+    fn base_units_exponent_bounds() {
+        // 1e18 * 1e-9 = 1e9 quote per base
+        assert_eq!(compute_base_units(3 * ONE as u128, Price::new(1, 18), 0, 0, Rounding::Floor), Ok(3));
+        // 1e-18 * 1e-9 = 1e-27 quote per base, 1e-9 once 18 quote decimals cancel
+        assert_eq!(compute_base_units(1, Price::new(1, -18), 0, 18, Rounding::Floor), Ok(ONE));
+    }
+
+    #[test]
+    fn base_units_overflow() {
+        // net = 30 + 9 + 18 = 57, past u128
+        assert_eq!(
+            compute_base_units(1, Price::new(1, -18), 30, 0, Rounding::Floor),
+            err(SwapError::InvalidParametersBaseScaleOverflow)
+        );
+        assert_eq!(
+            compute_base_units(u64::MAX as u128, Price::scaled(1), 21, 0, Rounding::Floor),
+            err(SwapError::InvalidParametersBaseUnitsCalculation)
+        );
+        assert_eq!(
+            compute_base_units(u64::MAX as u128, Price::scaled(1), 0, 0, Rounding::Floor),
+            err(SwapError::InvalidParametersBaseUnitsResult)
+        );
+        assert_eq!(
+            compute_base_units(1, Price::scaled(0), 0, 0, Rounding::Floor),
+            err(SwapError::InvalidParametersPriceScaledZero)
+        );
+    }
+
+    #[test]
+    fn base_units_rounding_at_half() {
+        // 3 and 5 quote at a price of 2 buy 1.5 and 2.5 base
+        let price = Price::scaled(2 * ONE);
+        assert_eq!(compute_base_units(3, price, 0, 0, Rounding::Floor), Ok(1));
+        assert_eq!(compute_base_units(3, price, 0, 0, Rounding::Ceil), Ok(2));
+        assert_eq!(compute_base_units(3, price, 0, 0, Rounding::Nearest), Ok(2));
+        assert_eq!(compute_base_units(5, price, 0, 0, Rounding::Floor), Ok(2));
+        assert_eq!(compute_base_units(5, price, 0, 0, Rounding::Ceil), Ok(3));
+        assert_eq!(compute_base_units(5, price, 0, 0, Rounding::Nearest), Ok(3));
+    }
+
+    #[test]
+    fn quote_units_net_exponent() {
+        // net = 0 + 0 - 0 - 9 = -9, rounded up
+        assert_eq!(compute_quote_units(3, Price::scaled(ONE), 0, 0), Ok(3));
+        assert_eq!(compute_quote_units(3, Price::scaled(ONE / 2), 0, 0), Ok(2));
+        // net = 18 + 0 - 0 - 9 = 9
+        assert_eq!(compute_quote_units(2, Price::scaled(ONE), 0, 18), Ok(2_000_000_000_000_000_000));
+    }
+
+    #[test]
+    fn quote_units_overflow() {
+        assert_eq!(
+            compute_quote_units(1, Price::scaled(ONE), 0, 60),
+            err(SwapError::InvalidParametersQuoteScaleOverflow)
+        );
+        assert_eq!(
+            compute_quote_units(u64::MAX, Price::scaled(u64::MAX), 0, 40),
+            err(SwapError::InvalidParametersQuoteUnitsOverflow)
+        );
+        assert_eq!(
+            compute_quote_units(u64::MAX, Price::scaled(u64::MAX), 0, 18),
+            err(SwapError::InvalidParametersQuoteUnitsOverflow)
+        );
+    }
+
+    #[test]
+    fn discount_keeps_small_mantissas() {
+        // 50% off a mantissa of 3 is 1.5, not 1
+        let price = Price::new(3, 0).discounted(5_000).unwrap();
+        assert_eq!(compute_base_units(3, price, 0, 0, Rounding::Floor), Ok(2 * ONE));
+        // 50% off a mantissa of 1 still prices the swap
+        let price = Price::new(1, 0).discounted(5_000).unwrap();
+        assert_eq!(compute_base_units(1, price, 0, 0, Rounding::Floor), Ok(2 * ONE));
+        // 3 base at 0.5 cost 1.5 quote, rounded up
+        let price = Price::scaled(ONE).discounted(5_000).unwrap();
+        assert_eq!(compute_quote_units(3, price, 0, 0), Ok(2));
+    }
+
+    #[test]
+    fn discount_must_leave_a_price() {
+        assert_eq!(
+            Price::scaled(ONE).discounted(10_000),
+            Err(SwapError::InvalidParametersVolumeDiscount.into())
+        );
+    }
+
+    #[test]
+    fn compare_across_exponents() {
+        assert_eq!(Price::new(1, 1).compare(&Price::new(10, 0)), Ordering::Equal);
+        assert_eq!(Price::new(2, -1).compare(&Price::new(1, 0)), Ordering::Less);
+        assert_eq!(Price::new(1, 0).compare(&Price::new(2, -1)), Ordering::Greater);
+        assert_eq!(Price::new(u64::MAX, 18).compare(&Price::new(1, -18)), Ordering::Greater);
+        assert_eq!(Price::new(1, -18).compare(&Price::new(u64::MAX, 18)), Ordering::Less);
+    }
+
+    #[test]
+    fn compare_with_discount() {
+        let discounted = Price::new(100, 0).discounted(5_000).unwrap();
+        assert_eq!(discounted.compare(&Price::new(50, 0)), Ordering::Equal);
+        assert_eq!(discounted.compare(&Price::new(5, 1)), Ordering::Equal);
+        assert_eq!(discounted.compare(&Price::new(51, 0)), Ordering::Less);
+    }
+
+    #[test]
+    fn swap_amounts_round_base_only() {
+        // 3 quote at a price of 2 with 1% quote and base bonuses
+        let amounts = swap_amounts(Price::scaled(2 * ONE), 0, ONE, ONE, 3, 0, 0, Rounding::Ceil).unwrap();
+        assert_eq!({ amounts.base_out }, 2);
+        assert_eq!({ amounts.quote_bonus }, 0);
+        assert_eq!({ amounts.base_bonus }, 0);
+        assert_eq!({ amounts.quote_to_vault }, 3);
+    }
+}
@@ -0,0 +1,64 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+use shank::ShankAccount;
+use pinocchio_log::log;
+use crate::{
+    errors::SwapError,
+    events::{emit, ConfigUpdated},
+    validation::{check_program_owner, check_signer, check_writable},
+    states::{
+        utils::{load_acc_mut, load_ix_data, DataLen},
+        Config,
+        MAX_QUOTE_MINTS,
+    },
+};
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct UpdateConfigData {
+    /// New admin (pass the current admin to keep it).
+    pub admin: Pubkey,
+    /// 1 to pause pool creation and swaps, 0 to resume.
+    pub paused: u8,
+    /// Number of used entries in `allowed_quote_mints` (0 = any quote mint).
+    pub allowed_quote_mints_len: u8,
+    pub allowed_quote_mints: [Pubkey; 8],
+}
+
+impl DataLen for UpdateConfigData {
+    const LEN: usize = core::mem::size_of::<UpdateConfigData>();
+}
+
+pub fn update_config(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    log!("Update Config");
+    let ix_data = unsafe { load_ix_data::<UpdateConfigData>(data)? };
+    let [admin_acc, config_acc] = accounts else {
+        return Err(SwapError::NotEnoughAccountKeysUpdateConfig.into());
+    };
+
+    // Validate admin is signer
+    check_signer(admin_acc, SwapError::MissingRequiredSignatureUpdateConfig)?;
+    check_writable(&[config_acc], SwapError::NotWritableUpdateConfig)?;
+    check_program_owner(&[config_acc], SwapError::WrongProgramOwnerUpdateConfig)?;
+
+    let config_state = unsafe { load_acc_mut::<Config>(config_acc) }?;
+    Config::validate_pda(config_state.bump_seed, config_acc.key())?;
+    if config_state.admin != *admin_acc.key() {
+        return Err(SwapError::NotAdminUpdateConfig.into());
+    }
+    if ix_data.allowed_quote_mints_len as usize > MAX_QUOTE_MINTS {
+        return Err(SwapError::InvalidParametersQuoteMintsLen.into());
+    }
+    config_state.admin = ix_data.admin;
+    config_state.paused = ix_data.paused != 0;
+    config_state.allowed_quote_mints_len = ix_data.allowed_quote_mints_len;
+    config_state.allowed_quote_mints = ix_data.allowed_quote_mints;
+
+    emit(&ConfigUpdated::new(config_state));
+    log!("Config paused: {}", config_state.paused);
+    log!("Config allowed_quote_mints_len: {}", ix_data.allowed_quote_mints_len);
+    Ok(())
+}
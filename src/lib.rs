@@ -7,7 +7,9 @@ mod entrypoint;
 extern crate std;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod states;
+pub mod validation;
 
 pinocchio_pubkey::declare_id!("SWAPmcsgGvfZMoHjp9wSMnGk5S2nVHxCwYAGfta9Vyp");
\ No newline at end of file
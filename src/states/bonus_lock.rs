@@ -0,0 +1,203 @@
+use super::utils::{create_pda_account, load_acc_mut, load_acc_mut_unchecked, DataLen, Discriminator};
+use shank::{ShankAccount, ShankType};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    ProgramResult,
+};
+
+use crate::errors::SwapError;
+
+/// Number of separately unlocking tranches a bonus lock holds (spelled out as a literal
+/// in `BonusLock::tranches` because shank cannot read constants).
+pub const MAX_LOCK_TRANCHES: usize = 8;
+
+/// Base bonus accrued within one unlock bucket. A tranche is free while `amount` is zero.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, ShankType)]
+pub struct LockTranche {
+    pub amount: u64,
+    pub unlock_ts: i64,
+}
+
+/// Base bonus accrued to a single recipient token account. Each accrual unlocks on its
+/// own schedule, so later accruals never push back earlier ones. The tokens themselves
+/// stay in the pool's base vault.
+///
+/// Rent is paid by the swap payer that opens the lock and goes to whoever closes it: the
+/// recipient owner on the claim that empties it, or the pool owner on `close`.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct BonusLock {
+    pub discriminator: u8,
+    pub version: u8,
+    pub swap: Pubkey,
+    pub recipient: Pubkey,
+    /// Total still locked or unclaimed across all tranches.
+    pub amount: u64,
+    /// Earliest unlock among the pending tranches (0 = none).
+    pub unlock_ts: i64,
+    pub bump_seed: u8,
+    pub tranches: [LockTranche; 8],
+}
+
+impl DataLen for BonusLock {
+    const LEN: usize = core::mem::size_of::<BonusLock>();
+}
+
+impl Discriminator for BonusLock {
+    const DISCRIMINATOR: u8 = 2;
+    const VERSION: u8 = 1;
+}
+
+impl BonusLock {
+    pub const SEED: &'static [u8] = b"lock";
+
+    pub fn find_pda(swap: &Pubkey, recipient: &Pubkey) -> (Pubkey, u8) {
+        find_program_address(&[Self::SEED, swap, recipient], &crate::ID)
+    }
+
+    pub fn validate_pda(bump_seed: u8, swap: &Pubkey, recipient: &Pubkey, pda: &Pubkey) -> Result<(), ProgramError> {
+        let derived = pinocchio_pubkey::derive_address(&[Self::SEED, swap, recipient], Some(bump_seed), &crate::ID);
+        if derived != *pda {
+            return Err(SwapError::InvalidPDAValidation.into());
+        }
+        Ok(())
+    }
+
+    /// Add `amount` to the recipient's lock, unlocking `lock_period` seconds after `now`,
+    /// creating the lock account on first use.
+    ///
+    /// Unlock times are rounded up to buckets of `lock_period / MAX_LOCK_TRANCHES`, and
+    /// accruals in the same bucket share a tranche. Once every tranche is taken, the
+    /// accrual joins the latest one, so only that tranche is pushed back.
+    pub fn accrue(
+        lock_acc: &AccountInfo,
+        swap_acc: &AccountInfo,
+        recipient_acc: &AccountInfo,
+        payer_acc: &AccountInfo,
+        amount: u64,
+        now: i64,
+        lock_period: i64,
+    ) -> ProgramResult {
+        if lock_acc.data_is_empty() {
+            let (pda, bump) = Self::find_pda(swap_acc.key(), recipient_acc.key());
+            if pda != *lock_acc.key() {
+                return Err(SwapError::InvalidPDAValidation.into());
+            }
+            let pda_bump_bytes = [bump];
+            let signer_seeds = [
+                Seed::from(Self::SEED),
+                Seed::from(swap_acc.key()),
+                Seed::from(recipient_acc.key()),
+                Seed::from(&pda_bump_bytes[..]),
+            ];
+            let signers = [Signer::from(&signer_seeds[..])];
+            create_pda_account(payer_acc, lock_acc, BonusLock::LEN, &signers)?;
+
+            let lock_data = unsafe { load_acc_mut_unchecked::<BonusLock>(lock_acc.borrow_mut_data_unchecked()) }?;
+            lock_data.discriminator = BonusLock::DISCRIMINATOR;
+            lock_data.version = BonusLock::VERSION;
+            lock_data.swap = *swap_acc.key();
+            lock_data.recipient = *recipient_acc.key();
+            lock_data.amount = 0;
+            lock_data.unlock_ts = 0;
+            lock_data.bump_seed = bump;
+            lock_data.tranches = [LockTranche::default(); MAX_LOCK_TRANCHES];
+        }
+
+        let lock_data = unsafe { load_acc_mut::<BonusLock>(lock_acc) }?;
+        Self::validate_pda(lock_data.bump_seed, swap_acc.key(), recipient_acc.key(), lock_acc.key())?;
+        if lock_data.swap != *swap_acc.key() || lock_data.recipient != *recipient_acc.key() {
+            return Err(SwapError::WrongBonusLockSwap.into());
+        }
+        lock_data.add_tranche(amount, unlock_bucket(now, lock_period))
+    }
+
+    /// Release the tranches unlocked at `now`, or every tranche when `now` is `None`.
+    /// Returns the amount released and whether the lock is now empty.
+    pub fn release(
+        lock_acc: &AccountInfo,
+        swap: &Pubkey,
+        recipient: &Pubkey,
+        now: Option<i64>,
+    ) -> Result<(u64, bool), ProgramError> {
+        let lock_data = unsafe { load_acc_mut::<BonusLock>(lock_acc) }?;
+        lock_data.check_lock(lock_acc, swap, recipient)?;
+        let mut released: u64 = 0;
+        for tranche in lock_data.tranches.iter_mut() {
+            if tranche.amount != 0 && now.is_none_or(|now| now >= tranche.unlock_ts) {
+                released = released
+                    .checked_add(tranche.amount)
+                    .ok_or(SwapError::InvalidParametersBonusLockedOverflow)?;
+                *tranche = LockTranche::default();
+            }
+        }
+        lock_data.amount = lock_data.amount.saturating_sub(released);
+        lock_data.refresh_unlock_ts();
+        Ok((released, lock_data.amount == 0))
+    }
+
+    fn check_lock(&self, lock_acc: &AccountInfo, swap: &Pubkey, recipient: &Pubkey) -> ProgramResult {
+        Self::validate_pda(self.bump_seed, swap, recipient, lock_acc.key())?;
+        if self.swap != *swap {
+            return Err(SwapError::WrongBonusLockSwap.into());
+        }
+        if self.recipient != *recipient {
+            return Err(SwapError::WrongRecipientClaimBonus.into());
+        }
+        Ok(())
+    }
+
+    fn add_tranche(&mut self, amount: u64, unlock_ts: i64) -> ProgramResult {
+        self.amount = self
+            .amount
+            .checked_add(amount)
+            .ok_or(SwapError::InvalidParametersBonusLockedOverflow)?;
+        let index = self
+            .tranches
+            .iter()
+            .position(|tranche| tranche.amount != 0 && tranche.unlock_ts == unlock_ts)
+            .or_else(|| self.tranches.iter().position(|tranche| tranche.amount == 0));
+        let tranche = match index {
+            Some(index) => &mut self.tranches[index],
+            None => self
+                .tranches
+                .iter_mut()
+                .max_by_key(|tranche| tranche.unlock_ts)
+                .ok_or(SwapError::InvalidParametersBonusLockedOverflow)?,
+        };
+        tranche.amount = tranche
+            .amount
+            .checked_add(amount)
+            .ok_or(SwapError::InvalidParametersBonusLockedOverflow)?;
+        tranche.unlock_ts = tranche.unlock_ts.max(unlock_ts);
+        self.refresh_unlock_ts();
+        Ok(())
+    }
+
+    fn refresh_unlock_ts(&mut self) {
+        self.unlock_ts = self
+            .tranches
+            .iter()
+            .filter(|tranche| tranche.amount != 0)
+            .map(|tranche| tranche.unlock_ts)
+            .min()
+            .unwrap_or(0);
+    }
+}
+
+/// `now + lock_period` rounded up to a multiple of `lock_period / MAX_LOCK_TRANCHES`
+/// (at least one second).
+fn unlock_bucket(now: i64, lock_period: i64) -> i64 {
+    let bucket = (lock_period / MAX_LOCK_TRANCHES as i64).max(1);
+    let unlock_ts = now.saturating_add(lock_period);
+    let rem = unlock_ts.rem_euclid(bucket);
+    if rem == 0 {
+        unlock_ts
+    } else {
+        unlock_ts.saturating_add(bucket - rem)
+    }
+}
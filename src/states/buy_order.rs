@@ -0,0 +1,115 @@
+use super::utils::{load_acc_mut_unchecked, DataLen, Discriminator};
+use shank::ShankAccount;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    ProgramResult,
+};
+
+use crate::errors::SwapError;
+
+/// Quote escrowed by a buyer until sellers fill it at `price`. The quote tokens are
+/// held in `vault_quote`, owned by the order PDA.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct BuyOrder {
+    pub discriminator: u8,
+    pub version: u8,
+    pub buyer: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub vault_quote: Pubkey,
+    /// Base token account receiving fills.
+    pub buyer_base: Pubkey,
+    pub uuid: u128,
+    /// 1e9-scaled limit price of 1 base in quote, same scale as `SwapState.price`.
+    pub price: u64,
+    /// Escrowed quote not yet paid to sellers.
+    pub quote_remaining: u64,
+    pub bump_seed: u8,
+}
+
+impl DataLen for BuyOrder {
+    const LEN: usize = core::mem::size_of::<BuyOrder>();
+}
+
+impl Discriminator for BuyOrder {
+    const DISCRIMINATOR: u8 = 7;
+    const VERSION: u8 = 1;
+}
+
+impl BuyOrder {
+    pub const SEED: &'static [u8] = b"buy";
+
+    pub fn find_pda(buyer: &Pubkey, uuid: u128) -> (Pubkey, u8) {
+        find_program_address(&[Self::SEED, buyer, &uuid.to_le_bytes()[..]], &crate::ID)
+    }
+
+    pub fn validate_pda(bump_seed: u8, buyer: &Pubkey, uuid: u128, pda: &Pubkey) -> Result<(), ProgramError> {
+        let derived = pinocchio_pubkey::derive_address(
+            &[Self::SEED, buyer, &uuid.to_le_bytes()[..]],
+            Some(bump_seed),
+            &crate::ID,
+        );
+        if derived != *pda {
+            return Err(SwapError::InvalidPDAValidation.into());
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_buy_order(
+        order_acc: &AccountInfo,
+        buyer_acc: &AccountInfo,
+        base_mint_acc: &AccountInfo,
+        quote_mint_acc: &AccountInfo,
+        vault_quote_acc: &AccountInfo,
+        buyer_base_acc: &AccountInfo,
+        uuid: u128,
+        price: u64,
+        quote_amount: u64,
+        bump_seed: u8,
+    ) -> ProgramResult {
+        let order_data = unsafe { load_acc_mut_unchecked::<BuyOrder>(order_acc.borrow_mut_data_unchecked()) }?;
+        order_data.discriminator = BuyOrder::DISCRIMINATOR;
+        order_data.version = BuyOrder::VERSION;
+        order_data.buyer = *buyer_acc.key();
+        order_data.base_mint = *base_mint_acc.key();
+        order_data.quote_mint = *quote_mint_acc.key();
+        order_data.vault_quote = *vault_quote_acc.key();
+        order_data.buyer_base = *buyer_base_acc.key();
+        order_data.uuid = uuid;
+        order_data.price = price;
+        order_data.quote_remaining = quote_amount;
+        order_data.bump_seed = bump_seed;
+        Ok(())
+    }
+}
+
+/// Owned copy of an order's signer seeds.
+pub struct BuyOrderSeeds {
+    buyer: Pubkey,
+    uuid: [u8; 16],
+    bump: [u8; 1],
+}
+
+impl BuyOrderSeeds {
+    pub fn new(order: &BuyOrder) -> Self {
+        Self {
+            buyer: order.buyer,
+            uuid: order.uuid.to_le_bytes(),
+            bump: [order.bump_seed],
+        }
+    }
+
+    pub fn seeds(&self) -> [Seed<'_>; 4] {
+        [
+            Seed::from(BuyOrder::SEED),
+            Seed::from(&self.buyer),
+            Seed::from(&self.uuid),
+            Seed::from(&self.bump),
+        ]
+    }
+}
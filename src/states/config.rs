@@ -0,0 +1,77 @@
+use super::utils::{load_acc_mut_unchecked, DataLen, Discriminator};
+use shank::ShankAccount;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    ProgramResult,
+};
+
+use crate::errors::SwapError;
+
+/// Maximum number of entries in the quote mint allow list (array lengths are
+/// spelled out as literals because shank cannot read constants).
+pub const MAX_QUOTE_MINTS: usize = 8;
+
+/// Program-wide settings, stored in a single PDA.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct Config {
+    pub discriminator: u8,
+    pub version: u8,
+    pub admin: Pubkey,
+    /// Wallet receiving protocol fees (token accounts must be owned by it for SPL quotes).
+    pub fee_treasury: Pubkey,
+    /// Protocol fee in basis points of `quote_in`.
+    pub fee_bps: u16,
+    /// Blocks pool creation and swaps while set.
+    pub paused: bool,
+    /// Number of used entries in `allowed_quote_mints` (0 = any quote mint).
+    pub allowed_quote_mints_len: u8,
+    pub allowed_quote_mints: [Pubkey; 8],
+    pub bump_seed: u8,
+}
+
+impl DataLen for Config {
+    const LEN: usize = core::mem::size_of::<Config>();
+}
+
+impl Discriminator for Config {
+    const DISCRIMINATOR: u8 = 4;
+    const VERSION: u8 = 1;
+}
+
+impl Config {
+    pub const SEED: &'static [u8] = b"config";
+
+    pub fn find_pda() -> (Pubkey, u8) {
+        find_program_address(&[Self::SEED], &crate::ID)
+    }
+
+    pub fn validate_pda(bump_seed: u8, pda: &Pubkey) -> Result<(), ProgramError> {
+        let derived = pinocchio_pubkey::derive_address(&[Self::SEED], Some(bump_seed), &crate::ID);
+        if derived != *pda {
+            return Err(SwapError::InvalidPDAValidation.into());
+        }
+        Ok(())
+    }
+
+    pub fn create_config(config_acc: &AccountInfo, admin_acc: &AccountInfo, bump_seed: u8) -> ProgramResult {
+        let config_data = unsafe { load_acc_mut_unchecked::<Config>(config_acc.borrow_mut_data_unchecked()) }?;
+        config_data.discriminator = Config::DISCRIMINATOR;
+        config_data.version = Config::VERSION;
+        config_data.admin = *admin_acc.key();
+        config_data.fee_treasury = *admin_acc.key();
+        config_data.fee_bps = 0;
+        config_data.paused = false;
+        config_data.allowed_quote_mints_len = 0;
+        config_data.allowed_quote_mints = [[0u8; 32]; MAX_QUOTE_MINTS];
+        config_data.bump_seed = bump_seed;
+        Ok(())
+    }
+
+    pub fn is_quote_mint_allowed(&self, mint: &Pubkey) -> bool {
+        let len = self.allowed_quote_mints_len as usize;
+        len == 0 || self.allowed_quote_mints[..len].contains(mint)
+    }
+}
@@ -0,0 +1,110 @@
+use super::utils::{load_acc_mut_unchecked, DataLen, Discriminator};
+use shank::{ShankAccount, ShankType};
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    ProgramResult,
+};
+
+use crate::errors::SwapError;
+
+/// Maximum number of open offers in one market (spelled out as a literal in
+/// `Market::offers` because shank cannot read constants).
+pub const MAX_MARKET_OFFERS: usize = 32;
+
+/// Offers must sell at least one whole base token divided by this, so filling the
+/// book with dust offers costs real tokens.
+pub const MIN_OFFER_BASE_DIVISOR: u64 = 1_000;
+
+/// Base tokens a seller has left for sale at a fixed price. A slot is free once
+/// `base_remaining` reaches zero.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct Offer {
+    pub seller: Pubkey,
+    /// Quote token account paid when the offer is filled.
+    pub seller_quote: Pubkey,
+    /// 1e9-scaled price of 1 base in quote, same scale as `SwapState.price`.
+    pub price: u64,
+    pub base_remaining: u64,
+}
+
+impl Offer {
+    pub fn is_open(&self) -> bool {
+        self.base_remaining != 0
+    }
+}
+
+/// Order book of sell offers for one (base, quote) pair. Offered base tokens are
+/// held in `vault_base`, owned by the market PDA.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct Market {
+    pub discriminator: u8,
+    pub version: u8,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub vault_base: Pubkey,
+    pub offers: [Offer; 32],
+    pub bump_seed: u8,
+}
+
+impl DataLen for Market {
+    const LEN: usize = core::mem::size_of::<Market>();
+}
+
+impl Discriminator for Market {
+    const DISCRIMINATOR: u8 = 6;
+    const VERSION: u8 = 1;
+}
+
+impl Market {
+    pub const SEED: &'static [u8] = b"market";
+
+    pub fn find_pda(base_mint: &Pubkey, quote_mint: &Pubkey) -> (Pubkey, u8) {
+        find_program_address(&[Self::SEED, base_mint, quote_mint], &crate::ID)
+    }
+
+    pub fn validate_pda(bump_seed: u8, base_mint: &Pubkey, quote_mint: &Pubkey, pda: &Pubkey) -> Result<(), ProgramError> {
+        let derived = pinocchio_pubkey::derive_address(&[Self::SEED, base_mint, quote_mint], Some(bump_seed), &crate::ID);
+        if derived != *pda {
+            return Err(SwapError::InvalidPDAValidation.into());
+        }
+        Ok(())
+    }
+
+    /// Smallest `base_amount` an offer may post for a base mint with `base_decimals`.
+    pub fn min_offer_base(base_decimals: u8) -> u64 {
+        10u64
+            .checked_pow(base_decimals as u32)
+            .map_or(u64::MAX, |unit| (unit / MIN_OFFER_BASE_DIVISOR).max(1))
+    }
+
+    /// Index of the highest-priced open offer, the first to go when the book is full.
+    pub fn worst_offer_index(&self) -> Option<usize> {
+        self.offers
+            .iter()
+            .enumerate()
+            .filter(|(_, offer)| offer.is_open())
+            .max_by_key(|(_, offer)| offer.price)
+            .map(|(index, _)| index)
+    }
+
+    pub fn create_market(
+        market_acc: &AccountInfo,
+        base_mint_acc: &AccountInfo,
+        quote_mint_acc: &AccountInfo,
+        vault_base_acc: &AccountInfo,
+        bump_seed: u8,
+    ) -> ProgramResult {
+        let market_data = unsafe { load_acc_mut_unchecked::<Market>(market_acc.borrow_mut_data_unchecked()) }?;
+        market_data.discriminator = Market::DISCRIMINATOR;
+        market_data.version = Market::VERSION;
+        market_data.base_mint = *base_mint_acc.key();
+        market_data.quote_mint = *quote_mint_acc.key();
+        market_data.vault_base = *vault_base_acc.key();
+        market_data.bump_seed = bump_seed;
+        Ok(())
+    }
+}
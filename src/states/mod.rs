@@ -1,5 +1,17 @@
 pub mod state;
+pub mod bonus_lock;
+pub mod buy_order;
+pub mod config;
+pub mod market;
+pub mod referrer;
+pub mod rfq_nonces;
 pub mod utils;
 
 pub use state::*;
-pub use utils::*;
\ No newline at end of file
+pub use bonus_lock::*;
+pub use buy_order::*;
+pub use config::*;
+pub use market::*;
+pub use referrer::*;
+pub use rfq_nonces::*;
+pub use utils::*;
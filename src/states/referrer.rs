@@ -0,0 +1,85 @@
+use super::utils::{load_acc_mut_unchecked, DataLen, Discriminator};
+use shank::ShankAccount;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    ProgramResult,
+};
+
+use super::SwapState;
+use crate::errors::SwapError;
+
+/// Registration of a wallet allowed to receive bonuses from a pool.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct Referrer {
+    pub discriminator: u8,
+    pub version: u8,
+    pub swap: Pubkey,
+    pub wallet: Pubkey,
+    pub approved: bool,
+    /// Whether `bonus_base`/`bonus_quote` override the pool's default rates.
+    pub custom_rates: bool,
+    pub bonus_base: u64,
+    pub bonus_quote: u64,
+    pub bump_seed: u8,
+}
+
+impl DataLen for Referrer {
+    const LEN: usize = core::mem::size_of::<Referrer>();
+}
+
+impl Discriminator for Referrer {
+    const DISCRIMINATOR: u8 = 3;
+    const VERSION: u8 = 1;
+}
+
+impl Referrer {
+    pub const SEED: &'static [u8] = b"referrer";
+
+    pub fn find_pda(swap: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+        find_program_address(&[Self::SEED, swap, wallet], &crate::ID)
+    }
+
+    pub fn validate_pda(bump_seed: u8, swap: &Pubkey, wallet: &Pubkey, pda: &Pubkey) -> Result<(), ProgramError> {
+        let derived = pinocchio_pubkey::derive_address(&[Self::SEED, swap, wallet], Some(bump_seed), &crate::ID);
+        if derived != *pda {
+            return Err(SwapError::InvalidPDAValidation.into());
+        }
+        Ok(())
+    }
+
+    pub fn create_referrer(
+        referrer_acc: &AccountInfo,
+        swap_acc: &AccountInfo,
+        wallet_acc: &AccountInfo,
+        bump_seed: u8,
+        approved: bool,
+    ) -> ProgramResult {
+        let referrer_data = unsafe { load_acc_mut_unchecked::<Referrer>(referrer_acc.borrow_mut_data_unchecked()) }?;
+        referrer_data.discriminator = Referrer::DISCRIMINATOR;
+        referrer_data.version = Referrer::VERSION;
+        referrer_data.swap = *swap_acc.key();
+        referrer_data.wallet = *wallet_acc.key();
+        referrer_data.approved = approved;
+        referrer_data.custom_rates = false;
+        referrer_data.bonus_base = 0;
+        referrer_data.bonus_quote = 0;
+        referrer_data.bump_seed = bump_seed;
+        Ok(())
+    }
+
+    /// Base and quote bonus percentages (1e9-scaled) paid to this referrer,
+    /// capped by the pool maximums.
+    pub fn bonus_rates(&self, swap_state: &SwapState) -> (u64, u64) {
+        if self.custom_rates {
+            (
+                self.bonus_base.min(swap_state.bonus_base_max),
+                self.bonus_quote.min(swap_state.bonus_quote_max),
+            )
+        } else {
+            (swap_state.bonus_base, swap_state.bonus_quote)
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use super::utils::{create_pda_account, load_acc_mut, load_acc_mut_unchecked, DataLen, Discriminator};
+use shank::ShankAccount;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    ProgramResult,
+};
+
+use crate::errors::SwapError;
+
+/// Nonces covered by one `RfqNonces` page.
+pub const RFQ_NONCES_PER_PAGE: u64 = 2048;
+
+/// Bitmap of spent RFQ nonces for one pool, covering nonces
+/// `page * RFQ_NONCES_PER_PAGE .. (page + 1) * RFQ_NONCES_PER_PAGE`.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
+pub struct RfqNonces {
+    pub discriminator: u8,
+    pub version: u8,
+    pub swap: Pubkey,
+    pub page: u64,
+    /// One bit per nonce, set once the nonce has been used (RFQ_NONCES_PER_PAGE / 8 bytes).
+    pub bits: [u8; 256],
+    pub bump_seed: u8,
+}
+
+impl DataLen for RfqNonces {
+    const LEN: usize = core::mem::size_of::<RfqNonces>();
+}
+
+impl Discriminator for RfqNonces {
+    const DISCRIMINATOR: u8 = 5;
+    const VERSION: u8 = 1;
+}
+
+impl RfqNonces {
+    pub const SEED: &'static [u8] = b"rfq";
+
+    pub fn find_pda(swap: &Pubkey, page: u64) -> (Pubkey, u8) {
+        find_program_address(&[Self::SEED, swap, &page.to_le_bytes()], &crate::ID)
+    }
+
+    pub fn validate_pda(bump_seed: u8, swap: &Pubkey, page: u64, pda: &Pubkey) -> Result<(), ProgramError> {
+        let derived = pinocchio_pubkey::derive_address(
+            &[Self::SEED, swap, &page.to_le_bytes()],
+            Some(bump_seed),
+            &crate::ID,
+        );
+        if derived != *pda {
+            return Err(SwapError::InvalidPDAValidation.into());
+        }
+        Ok(())
+    }
+
+    /// Mark `nonce` as used, creating its page on first use. Fails if it was already used.
+    pub fn use_nonce(
+        nonces_acc: &AccountInfo,
+        swap_acc: &AccountInfo,
+        payer_acc: &AccountInfo,
+        nonce: u64,
+    ) -> ProgramResult {
+        let page = nonce / RFQ_NONCES_PER_PAGE;
+        if nonces_acc.data_is_empty() {
+            let (pda, bump) = Self::find_pda(swap_acc.key(), page);
+            if pda != *nonces_acc.key() {
+                return Err(SwapError::InvalidPDAValidation.into());
+            }
+            let page_bytes = page.to_le_bytes();
+            let pda_bump_bytes = [bump];
+            let signer_seeds = [
+                Seed::from(Self::SEED),
+                Seed::from(swap_acc.key()),
+                Seed::from(&page_bytes),
+                Seed::from(&pda_bump_bytes[..]),
+            ];
+            let signers = [Signer::from(&signer_seeds[..])];
+            create_pda_account(payer_acc, nonces_acc, RfqNonces::LEN, &signers)?;
+
+            let nonces_data = unsafe { load_acc_mut_unchecked::<RfqNonces>(nonces_acc.borrow_mut_data_unchecked()) }?;
+            nonces_data.discriminator = RfqNonces::DISCRIMINATOR;
+            nonces_data.version = RfqNonces::VERSION;
+            nonces_data.swap = *swap_acc.key();
+            nonces_data.page = page;
+            nonces_data.bump_seed = bump;
+        }
+
+        let nonces_data = unsafe { load_acc_mut::<RfqNonces>(nonces_acc) }?;
+        Self::validate_pda(nonces_data.bump_seed, swap_acc.key(), page, nonces_acc.key())?;
+        if nonces_data.swap != *swap_acc.key() || nonces_data.page != page {
+            return Err(SwapError::WrongNoncesRfqSwap.into());
+        }
+        let bit = nonce % RFQ_NONCES_PER_PAGE;
+        let (byte, mask) = ((bit / 8) as usize, 1u8 << (bit % 8));
+        if nonces_data.bits[byte] & mask != 0 {
+            return Err(SwapError::NonceUsedRfqSwap.into());
+        }
+        nonces_data.bits[byte] |= mask;
+        Ok(())
+    }
+}
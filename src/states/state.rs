@@ -1,9 +1,10 @@
-use super::utils::{load_acc_mut_unchecked, DataLen};
-use shank::ShankAccount;
+use super::utils::{load_acc_mut_unchecked, DataLen, Discriminator};
+use shank::{ShankAccount, ShankType};
 use pinocchio::{
     account_info::AccountInfo,
+    instruction::Seed,
     program_error::ProgramError,
-    pubkey::Pubkey,
+    pubkey::{find_program_address, Pubkey},
     ProgramResult,
 };
 use pinocchio_log::log;
@@ -11,9 +12,72 @@ use pinocchio_log::log;
 
 use crate::{errors::SwapError, instructions::CreateData};
 
+/// Range of `SwapState::price_exponent`.
+pub const MIN_PRICE_EXPONENT: i8 = -18;
+pub const MAX_PRICE_EXPONENT: i8 = 18;
+
+/// Number of volume discount tiers a pool can define (spelled out as a literal in
+/// `SwapState::volume_tiers` because shank cannot read constants).
+pub const MAX_VOLUME_TIERS: usize = 4;
+
+/// Discount off the pool price for swaps paying at least `min_quote_in`. A tier is
+/// unused while `discount_bps` is zero.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, ShankType)]
+pub struct VolumeTier {
+    pub min_quote_in: u64,
+    /// Discount in basis points, below 10_000.
+    pub discount_bps: u16,
+}
+
+impl VolumeTier {
+    pub fn is_set(&self) -> bool {
+        self.discount_bps != 0
+    }
+}
+
+/// How a pool rounds the base it sells. `Floor` favours the pool, `Ceil` favours the
+/// buyer, `Nearest` rounds half up. Bonuses always round down, in the pool's favour.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, ShankType)]
+pub enum Rounding {
+    Floor,
+    Ceil,
+    Nearest,
+}
+
+impl TryFrom<u8> for Rounding {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Rounding::Floor),
+            1 => Ok(Rounding::Ceil),
+            2 => Ok(Rounding::Nearest),
+            _ => Err(SwapError::InvalidParametersRounding.into()),
+        }
+    }
+}
+
+impl Rounding {
+    /// `num / den` rounded in this direction. `den` must not be zero.
+    #[inline(always)]
+    pub fn div(self, num: u128, den: u128) -> u128 {
+        let (quotient, remainder) = (num / den, num % den);
+        let round_up = match self {
+            Rounding::Floor => false,
+            Rounding::Ceil => remainder != 0,
+            Rounding::Nearest => remainder >= den - remainder,
+        };
+        quotient + round_up as u128
+    }
+}
+
 #[repr(C, packed)]
 #[derive(Clone, Copy, Debug, PartialEq, ShankAccount)]
 pub struct SwapState {
+    pub discriminator: u8,
+    pub version: u8,
     pub owner: Pubkey,
     pub base: Pubkey,
     pub quote: Pubkey,
@@ -23,13 +87,87 @@ pub struct SwapState {
     pub bonus_quote: u64,
     pub bump_seed: u8,
     pub quote_sol: bool,
+    /// Seconds a base bonus stays locked before its recipient can claim it (0 = paid instantly).
+    pub bonus_lock_period: i64,
+    /// Base tokens held in the vault on behalf of outstanding bonus locks.
+    pub bonus_locked: u64,
+    /// Whether newly registered referrers need owner approval before they earn bonuses.
+    pub referrer_approval: bool,
+    /// Highest base bonus percentage a referrer may be granted (1e9-scaled, same as `bonus_base`).
+    pub bonus_base_max: u64,
+    /// Highest quote bonus percentage a referrer may be granted (1e9-scaled, same as `bonus_quote`).
+    pub bonus_quote_max: u64,
+    /// Which seeds derive this pool's PDA (`SEED_SCHEME_LEGACY` or `SEED_SCHEME_OWNER`).
+    pub seed_scheme: u8,
+    /// Quote tokens paid into the quote vault over the pool's lifetime.
+    pub total_quote_received: u64,
+    /// Base tokens sold to buyers over the pool's lifetime, bonuses excluded.
+    pub total_base_sold: u64,
+    /// Base bonuses paid out or accrued to bonus locks.
+    pub total_base_bonus_paid: u64,
+    /// Quote bonuses paid to referrers.
+    pub total_quote_bonus_paid: u64,
+    pub swap_count: u64,
+    /// Slot of the most recent swap (0 = never swapped).
+    pub last_swap_slot: u64,
+    /// Volume discounts, set tiers first in ascending `min_quote_in` order.
+    pub volume_tiers: [VolumeTier; 4],
+    /// `Rounding` applied to base out.
+    pub rounding: u8,
+    /// Smallest `quote_in` accepted by a swap (0 = no minimum).
+    pub min_quote_in: u64,
+    /// Power of ten `price` is multiplied by, so the price is `price * 10^price_exponent`
+    /// (1e9-scaled). Migrated legacy pools keep 0, the plain `price`.
+    pub price_exponent: i8,
+    /// Slots for a full throttle to drain (0 = unthrottled). Time-based windows are not
+    /// supported, so the window's length in seconds varies with slot times.
+    pub throttle_window_slots: u64,
+    /// Most base tokens the throttle holds; it drains by this much per `throttle_window_slots`.
+    pub throttle_max_base: u64,
+    /// Slot `throttle_base_sold` was last updated at.
+    pub throttle_last_slot: u64,
+    /// Recently sold base still counted against the throttle, as of `throttle_last_slot`.
+    pub throttle_base_sold: u64,
 }
 
 impl DataLen for SwapState {
     const LEN: usize = core::mem::size_of::<SwapState>();
 }
 
+impl Discriminator for SwapState {
+    const DISCRIMINATOR: u8 = 1;
+    const VERSION: u8 = 1;
+}
+
+/// Pool layout written before accounts carried a discriminator and version.
+/// Only read by the migration instruction.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LegacySwapState {
+    pub owner: Pubkey,
+    pub base: Pubkey,
+    pub quote: Pubkey,
+    pub uuid: u128,
+    pub price: u64,
+    pub bonus_base: u64,
+    pub bonus_quote: u64,
+    pub bump_seed: u8,
+    pub quote_sol: bool,
+}
+
+impl DataLen for LegacySwapState {
+    const LEN: usize = core::mem::size_of::<LegacySwapState>();
+}
+
 impl SwapState {
+    /// Seed prefix of owner-namespaced pools: `["swap", owner, uuid]`.
+    pub const SEED: &'static [u8] = b"swap";
+    /// Pools derived from `[uuid]` alone, created before owner namespacing.
+    pub const SEED_SCHEME_LEGACY: u8 = 0;
+    /// Pools derived from `["swap", owner, uuid]`.
+    pub const SEED_SCHEME_OWNER: u8 = 1;
+
+    /// Validate a legacy pool address derived from `[uuid]`.
     pub fn validate_pda(bump_seed: u8, uuid: u128, pda: &Pubkey) -> Result<(), ProgramError> {
         let derived = pinocchio_pubkey::derive_address(&[&uuid.to_le_bytes()[..]], Some(bump_seed), &crate::ID);
         /* let pda_b58 = pda.to_base58();
@@ -42,6 +180,101 @@ impl SwapState {
         Ok(())
     }
 
+    /// Canonical address and bump of an owner-namespaced pool.
+    pub fn find_pda(owner: &Pubkey, uuid: u128) -> (Pubkey, u8) {
+        find_program_address(&[Self::SEED, owner, &uuid.to_le_bytes()[..]], &crate::ID)
+    }
+
+    /// Validate a pool address derived from `["swap", owner, uuid]`.
+    pub fn validate_owner_pda(bump_seed: u8, owner: &Pubkey, uuid: u128, pda: &Pubkey) -> Result<(), ProgramError> {
+        let derived = pinocchio_pubkey::derive_address(
+            &[Self::SEED, owner, &uuid.to_le_bytes()[..]],
+            Some(bump_seed),
+            &crate::ID,
+        );
+        if derived != *pda {
+            return Err(SwapError::InvalidPDAValidation.into());
+        }
+        Ok(())
+    }
+
+    /// Re-derive this pool's address with the seed scheme it was created under.
+    pub fn check_pda(&self, pda: &Pubkey) -> Result<(), ProgramError> {
+        if self.seed_scheme == Self::SEED_SCHEME_OWNER {
+            Self::validate_owner_pda(self.bump_seed, &self.owner, self.uuid, pda)
+        } else {
+            Self::validate_pda(self.bump_seed, self.uuid, pda)
+        }
+    }
+
+    /// Add one swap to the pool's lifetime statistics.
+    pub fn record_swap(
+        &mut self,
+        quote_received: u64,
+        base_sold: u64,
+        base_bonus: u64,
+        quote_bonus: u64,
+        slot: u64,
+    ) -> ProgramResult {
+        let overflow = || ProgramError::from(SwapError::InvalidParametersStatsOverflow);
+        self.total_quote_received = self.total_quote_received.checked_add(quote_received).ok_or_else(overflow)?;
+        self.total_base_sold = self.total_base_sold.checked_add(base_sold).ok_or_else(overflow)?;
+        self.total_base_bonus_paid = self.total_base_bonus_paid.checked_add(base_bonus).ok_or_else(overflow)?;
+        self.total_quote_bonus_paid = self.total_quote_bonus_paid.checked_add(quote_bonus).ok_or_else(overflow)?;
+        self.swap_count = self.swap_count.checked_add(1).ok_or_else(overflow)?;
+        self.last_swap_slot = slot;
+        Ok(())
+    }
+
+    /// Deepest volume tier reached by `quote_in`, as its 1-based number and discount.
+    /// Returns `(0, 0)` when no tier applies.
+    pub fn volume_discount(&self, quote_in: u64) -> (u8, u16) {
+        let mut applied = (0, 0);
+        for (i, tier) in self.volume_tiers.iter().enumerate() {
+            if !tier.is_set() || tier.min_quote_in > quote_in {
+                break;
+            }
+            applied = (i as u8 + 1, tier.discount_bps);
+        }
+        applied
+    }
+
+    /// Base tokens in a vault holding `vault_amount` that the pool can sell. Base held for
+    /// outstanding bonus locks is not available for sale.
+    pub fn available_base(&self, vault_amount: u64) -> u64 {
+        vault_amount.saturating_sub(self.bonus_locked)
+    }
+
+    /// Base tokens that can still be sold at `slot` before the throttle kicks in.
+    pub fn throttle_allowance(&self, slot: u64) -> u64 {
+        if self.throttle_window_slots == 0 {
+            return u64::MAX;
+        }
+        self.throttle_max_base.saturating_sub(self.throttle_pending(slot))
+    }
+
+    /// Count `base_sold` against the throttle at `slot`.
+    pub fn record_throttle(&mut self, base_sold: u64, slot: u64) -> ProgramResult {
+        if self.throttle_window_slots == 0 {
+            return Ok(());
+        }
+        self.throttle_base_sold = self
+            .throttle_pending(slot)
+            .checked_add(base_sold)
+            .ok_or(SwapError::InvalidParametersStatsOverflow)?;
+        self.throttle_last_slot = slot;
+        Ok(())
+    }
+
+    /// Sold base still counted at `slot`. The count drains linearly, by `throttle_max_base`
+    /// every `throttle_window_slots` slots, so there is no window boundary at which the
+    /// full cap frees up at once; the drain is rounded down, against the buyer.
+    fn throttle_pending(&self, slot: u64) -> u64 {
+        let elapsed = slot.saturating_sub(self.throttle_last_slot) as u128;
+        let drained = elapsed * self.throttle_max_base as u128 / self.throttle_window_slots as u128;
+        (self.throttle_base_sold as u128).saturating_sub(drained) as u64
+    }
+
     pub fn create_swap(
         swap_acc: &AccountInfo,
         owner_acc: &AccountInfo,
@@ -52,9 +285,31 @@ impl SwapState {
         quote_owner: Pubkey,
     ) -> ProgramResult {
         let swap_data = unsafe { load_acc_mut_unchecked::<SwapState>(swap_acc.borrow_mut_data_unchecked()) }?;
+        swap_data.discriminator = SwapState::DISCRIMINATOR;
+        swap_data.version = SwapState::VERSION;
         swap_data.price = create_data.price;
+        swap_data.price_exponent = create_data.price_exponent;
         swap_data.bonus_base = create_data.bonus_base;
         swap_data.bonus_quote = create_data.bonus_quote;
+        swap_data.bonus_lock_period = create_data.bonus_lock_period;
+        swap_data.bonus_locked = 0;
+        swap_data.referrer_approval = create_data.referrer_approval != 0;
+        swap_data.bonus_base_max = create_data.bonus_base_max;
+        swap_data.bonus_quote_max = create_data.bonus_quote_max;
+        swap_data.seed_scheme = SwapState::SEED_SCHEME_OWNER;
+        swap_data.total_quote_received = 0;
+        swap_data.total_base_sold = 0;
+        swap_data.total_base_bonus_paid = 0;
+        swap_data.total_quote_bonus_paid = 0;
+        swap_data.swap_count = 0;
+        swap_data.last_swap_slot = 0;
+        swap_data.volume_tiers = [VolumeTier::default(); MAX_VOLUME_TIERS];
+        swap_data.rounding = Rounding::Floor as u8;
+        swap_data.min_quote_in = 0;
+        swap_data.throttle_window_slots = 0;
+        swap_data.throttle_max_base = 0;
+        swap_data.throttle_last_slot = 0;
+        swap_data.throttle_base_sold = 0;
         swap_data.uuid = create_data.uuid;
         swap_data.bump_seed = create_data.bump_seed;
         swap_data.owner = *owner_acc.key();
@@ -69,9 +324,201 @@ impl SwapState {
         log!("SwapState uuid: {}", swap_data.uuid);
         log!("SwapState quote_sol: {}", swap_data.quote_sol);
         log!("SwapState price: {}", swap_data.price / 1_000_000_000);
+        log!("SwapState price_exponent: {}", swap_data.price_exponent);
         log!("SwapState bonus_base: {}%", swap_data.bonus_base / 1_000_000_000);
         log!("SwapState bonus_quote: {}%", swap_data.bonus_quote / 1_000_000_000);
+        log!("SwapState bonus_lock_period: {}s", swap_data.bonus_lock_period);
+        log!("SwapState referrer_approval: {}", swap_data.referrer_approval);
 
         Ok(())
     }
+
+    /// Rewrite a legacy pool in the current layout. The account must already be
+    /// resized to `SwapState::LEN`; settings added since then take neutral defaults.
+    pub fn migrate_legacy(swap_acc: &AccountInfo, legacy: &LegacySwapState) -> ProgramResult {
+        let swap_data = unsafe { load_acc_mut_unchecked::<SwapState>(swap_acc.borrow_mut_data_unchecked()) }?;
+        swap_data.discriminator = SwapState::DISCRIMINATOR;
+        swap_data.version = SwapState::VERSION;
+        swap_data.owner = legacy.owner;
+        swap_data.base = legacy.base;
+        swap_data.quote = legacy.quote;
+        swap_data.uuid = legacy.uuid;
+        swap_data.price = legacy.price;
+        swap_data.bonus_base = legacy.bonus_base;
+        swap_data.bonus_quote = legacy.bonus_quote;
+        swap_data.bump_seed = legacy.bump_seed;
+        swap_data.quote_sol = legacy.quote_sol;
+        swap_data.bonus_lock_period = 0;
+        swap_data.bonus_locked = 0;
+        swap_data.referrer_approval = false;
+        swap_data.bonus_base_max = legacy.bonus_base;
+        swap_data.bonus_quote_max = legacy.bonus_quote;
+        swap_data.seed_scheme = SwapState::SEED_SCHEME_LEGACY;
+        swap_data.total_quote_received = 0;
+        swap_data.total_base_sold = 0;
+        swap_data.total_base_bonus_paid = 0;
+        swap_data.total_quote_bonus_paid = 0;
+        swap_data.swap_count = 0;
+        swap_data.last_swap_slot = 0;
+        swap_data.volume_tiers = [VolumeTier::default(); MAX_VOLUME_TIERS];
+        swap_data.rounding = Rounding::Floor as u8;
+        swap_data.min_quote_in = 0;
+        swap_data.throttle_window_slots = 0;
+        swap_data.throttle_max_base = 0;
+        swap_data.throttle_last_slot = 0;
+        swap_data.throttle_base_sold = 0;
+        swap_data.price_exponent = 0;
+        Ok(())
+    }
+}
+
+/// Owned copy of a pool's signer seeds, valid for either seed scheme.
+pub struct SwapSeeds {
+    owner: Pubkey,
+    uuid: [u8; 16],
+    bump: [u8; 1],
+    seed_scheme: u8,
+}
+
+impl SwapSeeds {
+    pub fn new(swap_state: &SwapState) -> Self {
+        Self {
+            owner: swap_state.owner,
+            uuid: swap_state.uuid.to_le_bytes(),
+            bump: [swap_state.bump_seed],
+            seed_scheme: swap_state.seed_scheme,
+        }
+    }
+
+    /// Signer seeds and how many of them are used: sign with `&seeds[..len]`.
+    pub fn seeds(&self) -> ([Seed<'_>; 4], usize) {
+        if self.seed_scheme == SwapState::SEED_SCHEME_OWNER {
+            (
+                [
+                    Seed::from(SwapState::SEED),
+                    Seed::from(&self.owner),
+                    Seed::from(&self.uuid),
+                    Seed::from(&self.bump),
+                ],
+                4,
+            )
+        } else {
+            (
+                [
+                    Seed::from(&self.uuid),
+                    Seed::from(&self.bump),
+                    Seed::from(&self.bump),
+                    Seed::from(&self.bump),
+                ],
+                2,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swap_state(bytes: &mut [u8; SwapState::LEN]) -> &mut SwapState {
+        unsafe { load_acc_mut_unchecked::<SwapState>(bytes) }.unwrap()
+    }
+
+    #[test]
+    fn rounding_div_at_half() {
+        assert_eq!(Rounding::Floor.div(5, 2), 2);
+        assert_eq!(Rounding::Ceil.div(5, 2), 3);
+        assert_eq!(Rounding::Nearest.div(5, 2), 3);
+        assert_eq!(Rounding::Nearest.div(7, 2), 4);
+    }
+
+    #[test]
+    fn rounding_div_off_half() {
+        assert_eq!(Rounding::Floor.div(7, 3), 2);
+        assert_eq!(Rounding::Ceil.div(7, 3), 3);
+        assert_eq!(Rounding::Nearest.div(7, 3), 2);
+        assert_eq!(Rounding::Nearest.div(8, 3), 3);
+    }
+
+    #[test]
+    fn rounding_div_exact() {
+        for rounding in [Rounding::Floor, Rounding::Ceil, Rounding::Nearest] {
+            assert_eq!(rounding.div(6, 3), 2);
+            assert_eq!(rounding.div(0, 3), 0);
+        }
+    }
+
+    #[test]
+    fn rounding_from_u8() {
+        assert_eq!(Rounding::try_from(2).unwrap(), Rounding::Nearest);
+        assert_eq!(Rounding::try_from(3), Err(SwapError::InvalidParametersRounding.into()));
+    }
+
+    #[test]
+    fn volume_discount_picks_deepest_reached_tier() {
+        let mut bytes = [0u8; SwapState::LEN];
+        let state = swap_state(&mut bytes);
+        state.volume_tiers[0] = VolumeTier { min_quote_in: 100, discount_bps: 50 };
+        state.volume_tiers[1] = VolumeTier { min_quote_in: 1_000, discount_bps: 200 };
+
+        assert_eq!(state.volume_discount(99), (0, 0));
+        assert_eq!(state.volume_discount(100), (1, 50));
+        assert_eq!(state.volume_discount(999), (1, 50));
+        assert_eq!(state.volume_discount(u64::MAX), (2, 200));
+    }
+
+    #[test]
+    fn volume_discount_without_tiers() {
+        let mut bytes = [0u8; SwapState::LEN];
+        assert_eq!(swap_state(&mut bytes).volume_discount(u64::MAX), (0, 0));
+    }
+
+    #[test]
+    fn throttle_allowance_unthrottled() {
+        let mut bytes = [0u8; SwapState::LEN];
+        assert_eq!(swap_state(&mut bytes).throttle_allowance(42), u64::MAX);
+    }
+
+    #[test]
+    fn throttle_allowance_drains_linearly() {
+        let mut bytes = [0u8; SwapState::LEN];
+        let state = swap_state(&mut bytes);
+        state.throttle_window_slots = 10;
+        state.throttle_max_base = 1_000;
+
+        assert_eq!(state.throttle_allowance(100), 1_000);
+        state.record_throttle(600, 100).unwrap();
+        assert_eq!(state.throttle_allowance(100), 400);
+        // 5 slots drain half the cap
+        assert_eq!(state.throttle_allowance(105), 900);
+        assert_eq!(state.throttle_allowance(110), 1_000);
+    }
+
+    #[test]
+    fn throttle_has_no_window_boundary() {
+        let mut bytes = [0u8; SwapState::LEN];
+        let state = swap_state(&mut bytes);
+        state.throttle_window_slots = 10;
+        state.throttle_max_base = 1_000;
+
+        // Selling the full cap late leaves almost nothing a slot later
+        state.record_throttle(1_000, 109).unwrap();
+        assert_eq!(state.throttle_allowance(109), 0);
+        assert_eq!(state.throttle_allowance(110), 100);
+        state.record_throttle(100, 110).unwrap();
+        assert_eq!(state.throttle_allowance(110), 0);
+    }
+
+    #[test]
+    fn throttle_drain_rounds_down() {
+        let mut bytes = [0u8; SwapState::LEN];
+        let state = swap_state(&mut bytes);
+        state.throttle_window_slots = 3;
+        state.throttle_max_base = 10;
+
+        state.record_throttle(10, 0).unwrap();
+        assert_eq!(state.throttle_allowance(1), 3);
+        assert_eq!(state.throttle_allowance(2), 6);
+        assert_eq!(state.throttle_allowance(3), 10);
+    }
 }
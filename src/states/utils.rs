@@ -1,10 +1,26 @@
-use pinocchio::program_error::ProgramError;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Signer,
+    program_error::ProgramError,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
 use crate::errors::SwapError;
 
 pub trait DataLen {
     const LEN: usize;
 }
 
+/// Program accounts start with a discriminator byte followed by a layout version byte.
+pub trait Discriminator: DataLen {
+    const DISCRIMINATOR: u8;
+    const VERSION: u8;
+}
+
+/// # Safety
+///
+/// `bytes` must hold a valid, properly initialized `T`.
 #[inline(always)]
 pub unsafe fn load_acc_unchecked<T: DataLen>(bytes: &[u8]) -> Result<&T, ProgramError> {
     if bytes.len() != T::LEN {
@@ -13,6 +29,9 @@ pub unsafe fn load_acc_unchecked<T: DataLen>(bytes: &[u8]) -> Result<&T, Program
     Ok(&*(bytes.as_ptr() as *const T))
 }
 
+/// # Safety
+///
+/// `bytes` must hold a valid, properly initialized `T`.
 #[inline(always)]
 pub unsafe fn load_acc_mut_unchecked<T: DataLen>(bytes: &mut [u8]) -> Result<&mut T, ProgramError> {
     if bytes.len() != T::LEN {
@@ -21,6 +40,51 @@ pub unsafe fn load_acc_mut_unchecked<T: DataLen>(bytes: &mut [u8]) -> Result<&mu
     Ok(&mut *(bytes.as_mut_ptr() as *mut T))
 }
 
+#[inline(always)]
+fn check_acc<T: Discriminator>(acc: &AccountInfo, bytes: &[u8]) -> Result<(), ProgramError> {
+    if !acc.is_owned_by(&crate::ID) {
+        return Err(SwapError::InvalidAccountDataOwner.into());
+    }
+    if bytes.len() != T::LEN {
+        return Err(SwapError::InvalidAccountDataLoadUnchecked.into());
+    }
+    if bytes[0] != T::DISCRIMINATOR {
+        return Err(SwapError::InvalidAccountDataDiscriminator.into());
+    }
+    if bytes[1] != T::VERSION {
+        return Err(SwapError::InvalidAccountDataVersion.into());
+    }
+    Ok(())
+}
+
+/// Load a program account after checking its owner, length, discriminator and version.
+///
+/// # Safety
+///
+/// The account data must not be mutably borrowed elsewhere.
+#[inline(always)]
+pub unsafe fn load_acc<T: Discriminator>(acc: &AccountInfo) -> Result<&T, ProgramError> {
+    let bytes = acc.borrow_data_unchecked();
+    check_acc::<T>(acc, bytes)?;
+    Ok(&*(bytes.as_ptr() as *const T))
+}
+
+/// Mutable variant of [`load_acc`].
+///
+/// # Safety
+///
+/// The account data must not be borrowed elsewhere.
+#[allow(clippy::mut_from_ref)]
+#[inline(always)]
+pub unsafe fn load_acc_mut<T: Discriminator>(acc: &AccountInfo) -> Result<&mut T, ProgramError> {
+    let bytes = acc.borrow_mut_data_unchecked();
+    check_acc::<T>(acc, bytes)?;
+    Ok(&mut *(bytes.as_mut_ptr() as *mut T))
+}
+
+/// # Safety
+///
+/// `bytes` must hold a valid, properly initialized `T`.
 #[inline(always)]
 pub unsafe fn load_ix_data<T: DataLen>(bytes: &[u8]) -> Result<&T, ProgramError> {
     if bytes.len() != T::LEN {
@@ -29,10 +93,58 @@ pub unsafe fn load_ix_data<T: DataLen>(bytes: &[u8]) -> Result<&T, ProgramError>
     Ok(&*(bytes.as_ptr() as *const T))
 }
 
+/// # Safety
+///
+/// `T` must be a plain `repr(C, packed)` type with no padding.
 pub unsafe fn to_bytes<T: DataLen>(data: &T) -> &[u8] {
     core::slice::from_raw_parts(data as *const T as *const u8, T::LEN)
 }
 
+/// # Safety
+///
+/// `T` must be a plain `repr(C, packed)` type with no padding.
 pub unsafe fn to_mut_bytes<T: DataLen>(data: &mut T) -> &mut [u8] {
     core::slice::from_raw_parts_mut(data as *mut T as *mut u8, T::LEN)
 }
+
+/// Create a rent-exempt, program-owned PDA of `space` bytes paid by `payer`. `CreateAccount`
+/// fails on an address that already holds lamports, so a pre-funded PDA is topped up to
+/// rent exemption, then allocated and assigned instead.
+pub fn create_pda_account(
+    payer: &AccountInfo,
+    account: &AccountInfo,
+    space: usize,
+    signers: &[Signer],
+) -> ProgramResult {
+    let required = Rent::get()?.minimum_balance(space);
+    let current = account.lamports();
+    if current == 0 {
+        return CreateAccount {
+            from: payer,
+            to: account,
+            space: space as u64,
+            owner: &crate::ID,
+            lamports: required,
+        }
+        .invoke_signed(signers);
+    }
+
+    if required > current {
+        Transfer {
+            from: payer,
+            to: account,
+            lamports: required - current,
+        }
+        .invoke()?;
+    }
+    Allocate {
+        account,
+        space: space as u64,
+    }
+    .invoke_signed(signers)?;
+    Assign {
+        account,
+        owner: &crate::ID,
+    }
+    .invoke_signed(signers)
+}
@@ -0,0 +1,30 @@
+use pinocchio::{account_info::AccountInfo, ProgramResult};
+use crate::errors::SwapError;
+
+// Account checks shared by every instruction. Each takes the error to report so
+// that failures stay attributable to the instruction that hit them.
+
+#[inline(always)]
+pub fn check_signer(acc: &AccountInfo, err: SwapError) -> ProgramResult {
+    if !acc.is_signer() {
+        return Err(err.into());
+    }
+    Ok(())
+}
+
+#[inline(always)]
+pub fn check_writable(accs: &[&AccountInfo], err: SwapError) -> ProgramResult {
+    if accs.iter().any(|acc| !acc.is_writable()) {
+        return Err(err.into());
+    }
+    Ok(())
+}
+
+/// The account must be owned by this program (checked before its data is trusted).
+#[inline(always)]
+pub fn check_program_owner(accs: &[&AccountInfo], err: SwapError) -> ProgramResult {
+    if accs.iter().any(|acc| !acc.is_owned_by(&crate::ID)) {
+        return Err(err.into());
+    }
+    Ok(())
+}
@@ -42,7 +42,9 @@ import { getCreateAccountInstruction } from '@solana-program/system';
 // Generated client imports
 import { getCreateInstruction } from './clients/js/src/generated/instructions/create';
 import { getSwapInstruction } from './clients/js/src/generated/instructions/swap';
+import { getCloseInstruction } from './clients/js/src/generated/instructions/close';
 import { AQUA_SWAP_PROGRAM_ADDRESS } from './clients/js/src/generated/programs/aquaSwap';
+import { findConfigPda, findSwapPda, withoutLockAccounts } from './aqua-swap-accounts';
 
 const RPC_URL = 'https://api.devnet.solana.com';
 const WS_URL = 'wss://api.devnet.solana.com';
@@ -70,10 +72,8 @@ async function testCloseInstruction() {
     const uuidStr = uuidv4();
     const uuidBytes = uuidParse(uuidStr);
     const uuid = toBigIntLE(Buffer.from(uuidBytes));
-    const [derivedSwapAddress, bumpSeed] = await getProgramDerivedAddress({
-      programAddress: AQUA_SWAP_PROGRAM_ADDRESS,
-      seeds: [uuidBytes],
-    });
+    const [derivedSwapAddress, bumpSeed] = await findSwapPda(payer.address, uuidBytes);
+    const [configAddress] = await findConfigPda();
 
     console.log(`🆔 UUID: ${uuidStr}`);
     console.log(`📦 Swap PDA: ${derivedSwapAddress}`);
@@ -213,9 +213,19 @@ async function testCloseInstruction() {
       swapAcc: address(derivedSwapAddress),
       vaultBaseAcc: address(baseAta),
       vaultQuoteAcc: address(quoteAta),
+      configAcc: configAddress,
       createData: {
         uuid,
         price: BigInt(1_000_000_000), // 1 base token = 1 quote token
+        bonusBase: 0n,
+        bonusQuote: 0n,
+        bonusBaseMax: 0n,
+        bonusQuoteMax: 0n,
+        bonusLockPeriod: 0n,
+        referrerApproval: 0,
+        createVault: 0,
+        initialDeposit: 0n,
+        priceExponent: 0,
         bumpSeed: Number(bumpSeed)
       }
     });
@@ -276,15 +286,39 @@ async function testCloseInstruction() {
       console.log(`   Owner base balance: ${ownerBalance} tokens`);
     }
 
-    // TODO: Add close instruction call here once client code is generated
-    console.log('⚠️  Close instruction not yet implemented in client code');
-    console.log('   The close instruction would:');
+    // Close the swap: no bonus locks to settle, so only the fixed accounts are passed
+    console.log('🔒 Closing swap account...');
     console.log('   1. Transfer all base tokens from vault to owner');
     console.log('   2. Close the base vault token account');
     console.log('   3. Close the swap account itself');
     console.log('   4. Return lamports to owner');
+    const closeIx = withoutLockAccounts(getCloseInstruction({
+      ownerAcc: payer,
+      swapAcc: address(derivedSwapAddress),
+      vaultBaseAcc: address(baseAta),
+      ownerBaseAcc: address(ownerBaseAta),
+      baseMintAcc: baseMint.address,
+    }));
+
+    const { value: latestBlockhash7 } = await rpc.getLatestBlockhash().send();
+    const closeSig = await pipe(
+      createTransactionMessage({ version: 0 }),
+      (tx) => setTransactionMessageFeePayerSigner(payer, tx),
+      (tx) => setTransactionMessageLifetimeUsingBlockhash(latestBlockhash7, tx),
+      (tx) => appendTransactionMessageInstructions([closeIx], tx),
+      async (tx) => {
+        const signed = await signTransactionMessageWithSigners(tx);
+        await sendAndConfirmTransaction(signed as any, { commitment: 'confirmed', skipPreflight: false });
+        return getSignatureFromTransaction(signed);
+      }
+    );
+    console.log('✅ Swap account closed:', closeSig);
+
+    const ownerAfterClose = await fetchToken(rpc, ownerBaseAta);
+    const ownerBalanceAfter = Number(ownerAfterClose.data?.amount || 0) / (10 ** DECIMALS);
+    console.log(`   Owner base balance after close: ${ownerBalanceAfter} tokens`);
 
-    console.log('\n🎉 Close instruction test setup completed!');
+    console.log('\n🎉 Close instruction test completed!');
     console.log('   Swap PDA:', derivedSwapAddress);
     console.log('   Base vault:', baseAta);
     console.log('   Owner base ATA:', ownerBaseAta);
@@ -1,5 +1,5 @@
 use std::mem;
-use mollusk_svm::result::{Check, ProgramResult};
+use mollusk_svm::result::{Check, InstructionResult, ProgramResult};
 use mollusk_svm::{program, Mollusk};
 use solana_sdk::account::Account;
 use solana_sdk::instruction::{AccountMeta, Instruction};
@@ -9,8 +9,14 @@ use solana_sdk::pubkey::Pubkey;
 extern crate alloc;
 use alloc::vec;
 
+use aqua_swap::errors::SwapError;
 use aqua_swap::instructions::create::CreateData;
+use aqua_swap::instructions::{RfqQuote, RfqSwapData};
 use aqua_swap::states::to_bytes;
+use aqua_swap::states::{
+    load_acc_mut_unchecked, load_acc_unchecked, Config, DataLen, Discriminator, RfqNonces, SwapState, RFQ_NONCES_PER_PAGE,
+};
+use solana_sdk::program_error::ProgramError;
 // use aqua_swap::states::DataLen;
 use solana_sdk::rent::Rent;
 // use solana_sdk::sysvar::Sysvar;
@@ -22,8 +28,7 @@ pub const RENT: Pubkey = pubkey!("SysvarRent111111111111111111111111111111111");
 pub const PAYER: Pubkey = pubkey!("FzUozk2MPhUfEuNzUZqPTTv1reHPhKqvmFhbBS2ph7R7");
 
 pub fn mollusk() -> Mollusk {
-    let mollusk = Mollusk::new(&PROGRAM, "target/deploy/aqua_swap");
-    mollusk
+    Mollusk::new(&PROGRAM, "target/deploy/aqua_swap")
 }
 
 pub fn get_rent_data() -> Vec<u8> {
@@ -43,13 +48,15 @@ fn test_initialize_swap() {
     // Create the PDA
     let uuid: u128 = 1000;
     let uuid_binding = uuid.to_le_bytes();
-    let (swap_pda, bump) = Pubkey::find_program_address(&[&uuid_binding[..]], &PROGRAM);
+    let (swap_pda, bump) = Pubkey::find_program_address(&[b"swap", PAYER.as_ref(), &uuid_binding[..]], &PROGRAM);
+    let (config_pda, _) = Pubkey::find_program_address(&[b"config"], &PROGRAM);
 
     //Initialize the accounts
-    let payer_account = Account::new(1 * LAMPORTS_PER_SOL, 0, &system_program);
+    let payer_account = Account::new(LAMPORTS_PER_SOL, 0, &system_program);
     let swap_account = Account::new(0, 0, &system_program);
     let base_account = Account::new(0, 0, &system_program);
     let quote_account = Account::new(0, 0, &system_program);
+    let config_account = Account::new(0, 0, &system_program);
     let min_balance = mollusk.sysvars.rent.minimum_balance(mem::size_of::<Rent>());
     let mut rent_account = Account::new(min_balance, mem::size_of::<Rent>(), &RENT);
     rent_account.data = get_rent_data();
@@ -62,13 +69,23 @@ fn test_initialize_swap() {
         AccountMeta::new_readonly(pubkey!("G9GUQuEKS6oJsZspUrAJ1aWFqp1SPq5tgCja4wpMueyX"), false),
         AccountMeta::new_readonly(system_program, false),
         AccountMeta::new_readonly(RENT, false),
+        AccountMeta::new_readonly(config_pda, false),
     ];
 
     // Create the instruction data
     let ix_data = CreateData {
         bump_seed: bump,
-        uuid: uuid,
+        uuid,
         price: 1,
+        bonus_base: 0,
+        bonus_quote: 0,
+        bonus_base_max: 0,
+        bonus_quote_max: 0,
+        bonus_lock_period: 0,
+        referrer_approval: 0,
+        create_vault: 0,
+        initial_deposit: 0,
+        price_exponent: 0,
     };
 
     // Ix discriminator = 0
@@ -84,16 +101,336 @@ fn test_initialize_swap() {
     let instruction = Instruction::new_with_bytes(PROGRAM, &ser_ix_data, ix_accounts);
 
     // Create tx_accounts vec
-    let tx_accounts = &vec![
+    let tx_accounts = &[
         (PAYER, payer_account.clone()),
         (swap_pda, swap_account.clone()),
         (pubkey!("G9GUQuEKS6oJsZspUrAJ1aWFqp1SPq5tgCja4wpMueyX"), base_account.clone()),
         (pubkey!("G9GUQuEKS6oJsZspUrAJ1aWFqp1SPq5tgCja4wpMueyX"), quote_account.clone()),
         (system_program, system_account.clone()),
         (RENT, rent_account.clone()),
+        (config_pda, config_account.clone()),
     ];
 
     let init_res = mollusk.process_and_validate_instruction(&instruction, tx_accounts, &[Check::success()]);
     assert!(init_res.program_result == ProgramResult::Success);
 }
         
+
+// RfqSwap checks the owner's ed25519 signature instruction and the nonce bitmap before
+// handing over to Swap. The fixture sets up a complete pool, so a valid quote swaps
+// through the SPL token program, loaded from `tests/fixtures/spl_token.so` (see README).
+
+pub const RFQ_PROGRAM: Pubkey = Pubkey::new_from_array(aqua_swap::ID);
+
+const ED25519_PROGRAM: Pubkey = pubkey!("Ed25519SigVerify111111111111111111111111111");
+
+const TOKEN_PROGRAM: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+const RFQ_EXPIRY: i64 = 1_000;
+
+const RFQ_QUOTE_IN: u64 = 100;
+
+struct RfqFixture {
+    owner: Pubkey,
+    buyer: Pubkey,
+    uuid: u128,
+    swap: (Pubkey, u8),
+    config: (Pubkey, u8),
+    base_mint: Pubkey,
+    quote_mint: Pubkey,
+    vault_base: Pubkey,
+    vault_quote: Pubkey,
+    user_base: Pubkey,
+    user_quote: Pubkey,
+    quote: RfqQuote,
+}
+
+impl RfqFixture {
+    fn new() -> Self {
+        let owner = Pubkey::new_unique();
+        let buyer = Pubkey::new_unique();
+        let uuid: u128 = 7;
+        let swap = Pubkey::find_program_address(&[b"swap", owner.as_ref(), &uuid.to_le_bytes()], &RFQ_PROGRAM);
+        Self {
+            owner,
+            buyer,
+            uuid,
+            swap,
+            config: Pubkey::find_program_address(&[b"config"], &RFQ_PROGRAM),
+            base_mint: Pubkey::new_unique(),
+            quote_mint: Pubkey::new_unique(),
+            vault_base: Pubkey::new_unique(),
+            vault_quote: Pubkey::new_unique(),
+            user_base: Pubkey::new_unique(),
+            user_quote: Pubkey::new_unique(),
+            quote: RfqQuote {
+                swap: swap.0.to_bytes(),
+                buyer: buyer.to_bytes(),
+                price: 1_000_000_000,
+                max_base_out: 1_000,
+                expiry: RFQ_EXPIRY,
+                nonce: 7,
+            },
+        }
+    }
+
+    fn nonces_pda(&self) -> (Pubkey, u8) {
+        let page = self.quote.nonce / RFQ_NONCES_PER_PAGE;
+        Pubkey::find_program_address(&[b"rfq", self.swap.0.as_ref(), &page.to_le_bytes()], &RFQ_PROGRAM)
+    }
+
+    fn swap_account(&self) -> Account {
+        let mut data = vec![0u8; SwapState::LEN];
+        let state = unsafe { load_acc_mut_unchecked::<SwapState>(&mut data) }.unwrap();
+        state.discriminator = SwapState::DISCRIMINATOR;
+        state.version = SwapState::VERSION;
+        state.owner = self.owner.to_bytes();
+        state.base = self.vault_base.to_bytes();
+        state.quote = self.vault_quote.to_bytes();
+        state.uuid = self.uuid;
+        state.price = 1_000_000_000;
+        state.bump_seed = self.swap.1;
+        state.seed_scheme = SwapState::SEED_SCHEME_OWNER;
+        program_account(data)
+    }
+
+    fn config_account(&self) -> Account {
+        let mut data = vec![0u8; Config::LEN];
+        let config = unsafe { load_acc_mut_unchecked::<Config>(&mut data) }.unwrap();
+        config.discriminator = Config::DISCRIMINATOR;
+        config.version = Config::VERSION;
+        config.bump_seed = self.config.1;
+        program_account(data)
+    }
+
+    /// Nonce page with the fixture's nonce already spent.
+    fn spent_nonces_account(&self) -> Account {
+        let (_, bump) = self.nonces_pda();
+        let mut data = vec![0u8; RfqNonces::LEN];
+        let nonces = unsafe { load_acc_mut_unchecked::<RfqNonces>(&mut data) }.unwrap();
+        nonces.discriminator = RfqNonces::DISCRIMINATOR;
+        nonces.version = RfqNonces::VERSION;
+        nonces.swap = self.swap.0.to_bytes();
+        nonces.page = self.quote.nonce / RFQ_NONCES_PER_PAGE;
+        nonces.bump_seed = bump;
+        let bit = self.quote.nonce % RFQ_NONCES_PER_PAGE;
+        nonces.bits[(bit / 8) as usize] |= 1 << (bit % 8);
+        program_account(data)
+    }
+
+    /// The RfqSwap instruction for the fixture's quote, with Swap's accounts after the
+    /// instructions sysvar and nonce page, and `PAYER` funding Swap's lamport moves.
+    fn rfq_instruction(&self) -> Instruction {
+        let ix_data = RfqSwapData { quote_in: RFQ_QUOTE_IN, quote: self.quote };
+        let mut ser_ix_data = vec![14u8];
+        ser_ix_data.extend_from_slice(unsafe { to_bytes(&ix_data) });
+        let (system_program, _) = program::keyed_account_for_system_program();
+        let accounts = vec![
+            AccountMeta::new_readonly(INSTRUCTIONS_SYSVAR, false),
+            AccountMeta::new(self.nonces_pda().0, false),
+            AccountMeta::new(self.buyer, true),
+            AccountMeta::new(self.swap.0, false),
+            AccountMeta::new(self.vault_base, false),
+            AccountMeta::new(self.vault_quote, false),
+            AccountMeta::new(self.user_base, false),
+            AccountMeta::new(self.user_quote, false),
+            AccountMeta::new_readonly(self.base_mint, false),
+            AccountMeta::new_readonly(self.quote_mint, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM, false),
+            AccountMeta::new_readonly(self.config.0, false),
+            AccountMeta::new(PAYER, true),
+            AccountMeta::new_readonly(system_program, false),
+        ];
+        Instruction::new_with_bytes(RFQ_PROGRAM, &ser_ix_data, accounts)
+    }
+
+    /// Ed25519 instruction with the owner's signature over the fixture's quote.
+    fn owner_signature(&self) -> Instruction {
+        ed25519_instruction(&self.owner, &self.message(), u16::MAX)
+    }
+
+    /// Run the fixture's RfqSwap right after `ed25519_ix` in the same transaction, with
+    /// `nonces` as the nonce page.
+    fn process(&self, mollusk: &Mollusk, ed25519_ix: &Instruction, nonces: Account, checks: &[Check]) -> InstructionResult {
+        let instruction = self.rfq_instruction();
+        let (system_program, system_account) = program::keyed_account_for_system_program();
+        let accounts = vec![
+            (INSTRUCTIONS_SYSVAR, instructions_sysvar_account(&[ed25519_ix, &instruction], 1)),
+            (self.nonces_pda().0, nonces),
+            (self.buyer, Account::new(LAMPORTS_PER_SOL, 0, &system_program)),
+            (self.swap.0, self.swap_account()),
+            (self.vault_base, token_account(&self.base_mint, &self.swap.0, 1_000)),
+            (self.vault_quote, token_account(&self.quote_mint, &self.owner, 0)),
+            (self.user_base, token_account(&self.base_mint, &self.buyer, 0)),
+            (self.user_quote, token_account(&self.quote_mint, &self.buyer, RFQ_QUOTE_IN)),
+            (self.base_mint, mint_account()),
+            (self.quote_mint, mint_account()),
+            (TOKEN_PROGRAM, program::create_program_account_loader_v3(&TOKEN_PROGRAM)),
+            (self.config.0, self.config_account()),
+            (PAYER, Account::new(LAMPORTS_PER_SOL, 0, &system_program)),
+            (system_program, system_account),
+        ];
+        mollusk.process_and_validate_instruction(&instruction, &accounts, checks)
+    }
+
+    fn message(&self) -> Vec<u8> {
+        unsafe { to_bytes(&self.quote) }.to_vec()
+    }
+}
+
+fn program_account(data: Vec<u8>) -> Account {
+    Account {
+        lamports: LAMPORTS_PER_SOL,
+        data,
+        owner: RFQ_PROGRAM,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// Initialized SPL token account: mint, owner and amount, then no delegate.
+fn token_account(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Account {
+    let mut data = vec![0u8; 165];
+    data[..32].copy_from_slice(mint.as_ref());
+    data[32..64].copy_from_slice(owner.as_ref());
+    data[64..72].copy_from_slice(&amount.to_le_bytes());
+    // AccountState::Initialized
+    data[108] = 1;
+    Account {
+        lamports: LAMPORTS_PER_SOL,
+        data,
+        owner: TOKEN_PROGRAM,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// Initialized SPL mint with 6 decimals and no authorities.
+fn mint_account() -> Account {
+    let mut data = vec![0u8; 82];
+    data[44] = 6;
+    data[45] = 1;
+    Account {
+        lamports: LAMPORTS_PER_SOL,
+        data,
+        owner: TOKEN_PROGRAM,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// Nonce page address before its first use.
+fn unused_nonces_account() -> Account {
+    let (system_program, _) = program::keyed_account_for_system_program();
+    Account::new(0, 0, &system_program)
+}
+
+const INSTRUCTIONS_SYSVAR: Pubkey = pubkey!("Sysvar1nstructions1111111111111111111111111");
+
+/// Ed25519 program instruction carrying one signature, key and message in its own data.
+/// `message_ix` is the instruction index the message offset points into (u16::MAX = this one).
+fn ed25519_instruction(public_key: &Pubkey, message: &[u8], message_ix: u16) -> Instruction {
+    const HEADER: u16 = 16;
+    const PUBLIC_KEY: u16 = HEADER;
+    const SIGNATURE: u16 = PUBLIC_KEY + 32;
+    const MESSAGE: u16 = SIGNATURE + 64;
+    let mut data = vec![1u8, 0];
+    for offset in [SIGNATURE, u16::MAX, PUBLIC_KEY, u16::MAX, MESSAGE, message.len() as u16, message_ix] {
+        data.extend_from_slice(&offset.to_le_bytes());
+    }
+    data.extend_from_slice(public_key.as_ref());
+    // The ed25519 program checks the signature itself; RfqSwap only reads the key and message
+    data.extend_from_slice(&[0u8; 64]);
+    data.extend_from_slice(message);
+    Instruction::new_with_bytes(ED25519_PROGRAM, &data, vec![])
+}
+
+#[allow(deprecated)]
+fn instructions_sysvar_account(instructions: &[&Instruction], current: u16) -> Account {
+    use solana_sdk::sysvar::instructions::{construct_instructions_data, BorrowedAccountMeta, BorrowedInstruction};
+    let borrowed: Vec<BorrowedInstruction> = instructions
+        .iter()
+        .map(|ix| BorrowedInstruction {
+            program_id: &ix.program_id,
+            accounts: ix
+                .accounts
+                .iter()
+                .map(|meta| BorrowedAccountMeta {
+                    pubkey: &meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: &ix.data,
+        })
+        .collect();
+    let mut data = construct_instructions_data(&borrowed);
+    let len = data.len();
+    data[len - 2..].copy_from_slice(&current.to_le_bytes());
+    Account {
+        lamports: LAMPORTS_PER_SOL,
+        data,
+        owner: pubkey!("Sysvar1111111111111111111111111111111111111"),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn rfq_error(error: SwapError) -> Check<'static> {
+    Check::err(ProgramError::Custom(error as u32))
+}
+
+fn rfq_mollusk() -> Mollusk {
+    let mut mollusk = Mollusk::new(&RFQ_PROGRAM, "target/deploy/aqua_swap");
+    mollusk.add_program(&TOKEN_PROGRAM, "spl_token", &program::loader_keys::LOADER_V3);
+    mollusk.sysvars.clock.unix_timestamp = RFQ_EXPIRY - 100;
+    mollusk
+}
+
+#[test]
+fn test_rfq_swap_marks_nonce_used() {
+    let mollusk = rfq_mollusk();
+    let fixture = RfqFixture::new();
+    let result = fixture.process(&mollusk, &fixture.owner_signature(), unused_nonces_account(), &[Check::success()]);
+
+    let nonces_acc = result.get_account(&fixture.nonces_pda().0).unwrap();
+    assert_eq!(nonces_acc.owner, RFQ_PROGRAM);
+    let nonces = unsafe { load_acc_unchecked::<RfqNonces>(&nonces_acc.data) }.unwrap();
+    let bit = fixture.quote.nonce % RFQ_NONCES_PER_PAGE;
+    assert_ne!(nonces.bits[(bit / 8) as usize] & (1 << (bit % 8)), 0);
+    assert_eq!({ nonces.page }, fixture.quote.nonce / RFQ_NONCES_PER_PAGE);
+}
+
+#[test]
+fn test_rfq_swap_wrong_signer() {
+    let mollusk = rfq_mollusk();
+    let fixture = RfqFixture::new();
+    let ed25519_ix = ed25519_instruction(&Pubkey::new_unique(), &fixture.message(), u16::MAX);
+    fixture.process(&mollusk, &ed25519_ix, unused_nonces_account(), &[rfq_error(SwapError::InvalidSignatureRfqSwap)]);
+}
+
+#[test]
+fn test_rfq_swap_offsets_into_other_instruction() {
+    let mollusk = rfq_mollusk();
+    let fixture = RfqFixture::new();
+    // The message offset points at the RfqSwap instruction (index 1), whose data also
+    // holds the quote, so the ed25519 program would have verified a different message
+    let ed25519_ix = ed25519_instruction(&fixture.owner, &fixture.message(), 1);
+    fixture.process(&mollusk, &ed25519_ix, unused_nonces_account(), &[rfq_error(SwapError::InvalidSignatureRfqSwap)]);
+}
+
+#[test]
+fn test_rfq_swap_replayed_nonce() {
+    let mollusk = rfq_mollusk();
+    let fixture = RfqFixture::new();
+    let nonces = fixture.spent_nonces_account();
+    fixture.process(&mollusk, &fixture.owner_signature(), nonces, &[rfq_error(SwapError::NonceUsedRfqSwap)]);
+}
+
+#[test]
+fn test_rfq_swap_expired_quote() {
+    let mut mollusk = rfq_mollusk();
+    mollusk.sysvars.clock.unix_timestamp = RFQ_EXPIRY + 1;
+    let fixture = RfqFixture::new();
+    fixture.process(&mollusk, &fixture.owner_signature(), unused_nonces_account(), &[rfq_error(SwapError::ExpiredRfqSwap)]);
+}